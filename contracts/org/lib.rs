@@ -4,11 +4,15 @@ pub use self::org::{Designation, Org, OrgRef};
 
 #[ink::contract]
 mod org {
+    use ink::codegen::EmitEvent;
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
+    use org_token::{OrgTokenRef, PSP22Metadata, PSP22};
     use pproposal::PproposalRef;
-    use project::{BusinessIdea, Error, ProjectRef, Result};
+    use project::{
+        normalize_skills, BusinessIdea, Error, ProjectRef, ProjectStatus, Result, VotingMode,
+    };
 
     #[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
     #[cfg_attr(
@@ -29,12 +33,35 @@ mod org {
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
     )]
-    //Todo: projectwise rating storage
     pub struct MemberInfo {
         name: String,
         company_id: u16,
         designation: Designation, // add designation enums
+        /// set while adding the member, replaced by reputation once project tasks are rated
         rating: Option<u8>,
+        /// skill tag ids declared by the member
+        skills: Vec<u16>,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct TaskRating {
+        project_id: u32,
+        task_id: u16,
+        rating: u8,
+    }
+
+    #[derive(Debug, Clone, Default, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Reputation {
+        rating_sum: u64,
+        rated_tasks: u64,
     }
     #[derive(Debug, Clone, scale::Encode, scale::Decode)]
     #[cfg_attr(
@@ -44,6 +71,65 @@ mod org {
     pub struct OutsiderInfo {
         name: String,
         account_address: AccountId,
+        skills: Vec<u16>,
+    }
+
+    #[ink(event)]
+    pub struct MemberAdded {
+        #[ink(topic)]
+        member: AccountId,
+        designation: Designation,
+    }
+
+    #[ink(event)]
+    pub struct MemberRemoved {
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    /// emitted on every designation change, including promotion to and demotion from secretariate
+    #[ink(event)]
+    pub struct DesignationChanged {
+        #[ink(topic)]
+        member: AccountId,
+        old_designation: Designation,
+        new_designation: Designation,
+    }
+
+    #[ink(event)]
+    pub struct ReputationUpdated {
+        #[ink(topic)]
+        member: AccountId,
+        #[ink(topic)]
+        project_id: u32,
+        rating: u8,
+    }
+
+    #[ink(event)]
+    pub struct ProjectCreated {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        project_address: AccountId,
+        #[ink(topic)]
+        manager: AccountId,
+        fund_allocated: u128,
+    }
+
+    #[ink(event)]
+    pub struct ProjectStatusRecorded {
+        #[ink(topic)]
+        project_id: u32,
+        status: ProjectStatus,
+    }
+
+    #[ink(event)]
+    pub struct FundTransferred {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        project_address: AccountId,
+        amount: u128,
     }
 
     #[ink(storage)]
@@ -53,18 +139,33 @@ mod org {
         secretariate: Vec<AccountId>, // store board members list(Added by proposal or something)
         secretariate_count: u64,
         member_count: u64,
+        /// all members including owner and secretariates
+        member_list: Vec<AccountId>,
+        /// sum of rating weights of all members and of secretariates (used in weighted voting)
+        member_weight: u64,
+        secretariate_weight: u64,
         org_members: Mapping<AccountId, MemberInfo>, //also contains board members
         next_project_id: u32,
         /// store created (project address -> manager address)
         projects_manager: Mapping<AccountId, AccountId>,
         /// Store projects by id, to iterate to find all the projects
         project_list_by_id: Mapping<u32, AccountId>,
+        /// last known lifecycle status of every project
+        project_status: Mapping<u32, ProjectStatus>,
+        /// registry of skill tags (tag id -> name)
+        skill_tags: Mapping<u16, String>,
+        next_skill_tag_id: u16,
+        /// ratings of all project tasks of the member
+        task_ratings: Mapping<AccountId, Vec<TaskRating>>,
+        reputation: Mapping<AccountId, Reputation>,
         // Don't need to store this because can call project fn using address
         // project: ProjectRef,
         // project_address: AccountId,
         project_codehash: Hash,
         project_proposal_codehash: Hash,
         proposal_address: Option<AccountId>,
+        /// PSP22 governance token instantiated by the organisation(if any)
+        token_address: Option<AccountId>,
     }
 
     impl Org {
@@ -82,9 +183,11 @@ mod org {
                 company_id,
                 designation: Designation::Owner,
                 rating,
+                skills: Vec::new(),
             };
 
             let creator = Self::env().caller();
+            let member_weight = Self::rating_weight(creator_info.rating);
             let mut org_members = Mapping::new();
             org_members.insert(creator, &creator_info);
 
@@ -94,13 +197,22 @@ mod org {
                 secretariate: Vec::new(),
                 secretariate_count: 0,
                 member_count: 1,
+                member_list: Vec::from([creator]),
+                member_weight,
+                secretariate_weight: 0,
                 org_members,
                 next_project_id: 1,
                 projects_manager: Mapping::new(),
                 project_list_by_id: Mapping::new(),
+                project_status: Mapping::new(),
+                skill_tags: Mapping::new(),
+                next_skill_tag_id: 1,
+                task_ratings: Mapping::new(),
+                reputation: Mapping::new(),
                 project_codehash,
                 project_proposal_codehash,
                 proposal_address: None,
+                token_address: None,
             }
         }
 
@@ -123,6 +235,36 @@ mod org {
             Ok(())
         }
 
+        /// instantiate the governance token of the organisation, can be done only once
+        #[ink(message)]
+        pub fn create_org_token(
+            &mut self,
+            token_codehash: Hash,
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Result<AccountId> {
+            let caller = self.env().caller();
+            if caller != self.org_owner {
+                return Err(Error::UnAuthorized);
+            }
+            if self.token_address.is_some() {
+                return Err(Error::TokenAlreadyExist);
+            }
+            let version: u8 = 99; // for random salt creation
+            let salt = version.to_be_bytes();
+
+            let token_instance = OrgTokenRef::new(Some(name), Some(symbol), decimals)
+                .endowment(0)
+                .code_hash(token_codehash)
+                .salt_bytes(salt)
+                .instantiate();
+            let token_address = token_instance.show_address();
+            self.token_address = Some(token_address);
+
+            Ok(token_address)
+        }
+
         #[ink(message)]
         pub fn add_member_in_orgainisation(
             &mut self,
@@ -133,22 +275,10 @@ mod org {
         ) -> Result<()> {
             let caller = self.env().caller();
             if caller == self.org_owner {
-                let member_info = MemberInfo {
-                    name,
-                    company_id,
-                    designation: Designation::Member,
-                    rating,
-                };
-                if let Some(_info) = self.org_members.get(member_address) {
-                    return Err(Error::AlreadyMember);
-                } else {
-                    self.org_members.insert(member_address, &member_info);
-                    self.member_count += 1;
-                }
+                self.insert_member(member_address, name, company_id, rating)
             } else {
                 return Err(Error::UnAuthorized);
             }
-            Ok(())
         }
 
         #[ink(message)]
@@ -161,34 +291,274 @@ mod org {
         ) -> Result<()> {
             let caller = self.env().caller();
             if caller == self.org_owner {
-                let secretariate_info = MemberInfo {
-                    name,
-                    company_id,
-                    designation: Designation::Secretariate,
-                    rating,
-                };
-                if let Some(info) = self.org_members.get(secretariate_address) {
-                    if info.designation == Designation::Secretariate {
-                        return Err(Error::AlreadySecretariate);
-                    } else {
-                        self.org_members
-                            .insert(secretariate_address, &secretariate_info);
-                        self.secretariate_count += 1;
-                        self.secretariate.push(secretariate_address);
-                    }
+                self.insert_secretariate(secretariate_address, name, company_id, rating)
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+        }
+
+        /// remove member from organisation(also removes from secretariate if they are one)
+        #[ink(message)]
+        pub fn remove_member(&mut self, member_address: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if caller == self.org_owner {
+                self.delete_member(member_address)
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+        }
+
+        #[ink(message)]
+        pub fn change_designation(
+            &mut self,
+            member_address: AccountId,
+            designation: Designation,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if caller == self.org_owner {
+                if let Some(info) = self.org_members.get(member_address) {
+                    self.update_designation(member_address, info, designation)?;
                 } else {
+                    return Err(Error::MemberNotFound);
+                }
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+            Ok(())
+        }
+
+        /// demote secretariate back to member
+        #[ink(message)]
+        pub fn remove_secretariate(&mut self, secretariate_address: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if caller == self.org_owner {
+                self.demote_secretariate(secretariate_address)
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+        }
+
+        // IMPORTANT IMPORTANT IMPORTANT IMPORTANT
+        // membership changes below are only called when the respective proposal passed
+
+        #[ink(message)]
+        pub fn add_member_by_proposal(
+            &mut self,
+            member_address: AccountId,
+            name: String,
+            company_id: u16,
+            rating: Option<u8>,
+        ) -> Result<()> {
+            if Some(self.env().caller()) == self.proposal_address {
+                self.insert_member(member_address, name, company_id, rating)
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+        }
+
+        #[ink(message)]
+        pub fn remove_member_by_proposal(&mut self, member_address: AccountId) -> Result<()> {
+            if Some(self.env().caller()) == self.proposal_address {
+                self.delete_member(member_address)
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+        }
+
+        #[ink(message)]
+        pub fn add_secretariate_by_proposal(
+            &mut self,
+            secretariate_address: AccountId,
+            name: String,
+            company_id: u16,
+            rating: Option<u8>,
+        ) -> Result<()> {
+            if Some(self.env().caller()) == self.proposal_address {
+                self.insert_secretariate(secretariate_address, name, company_id, rating)
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+        }
+
+        #[ink(message)]
+        pub fn remove_secretariate_by_proposal(
+            &mut self,
+            secretariate_address: AccountId,
+        ) -> Result<()> {
+            if Some(self.env().caller()) == self.proposal_address {
+                self.demote_secretariate(secretariate_address)
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+        }
+
+        fn insert_member(
+            &mut self,
+            member_address: AccountId,
+            name: String,
+            company_id: u16,
+            rating: Option<u8>,
+        ) -> Result<()> {
+            let member_info = MemberInfo {
+                name,
+                company_id,
+                designation: Designation::Member,
+                rating,
+                skills: Vec::new(),
+            };
+            if let Some(_info) = self.org_members.get(member_address) {
+                return Err(Error::AlreadyMember);
+            } else {
+                self.org_members.insert(member_address, &member_info);
+                self.member_list.push(member_address);
+                self.member_count += 1;
+                self.member_weight += Self::rating_weight(rating);
+                EmitEvent::<Org>::emit_event(self.env(), MemberAdded {
+                    member: member_address,
+                    designation: Designation::Member,
+                });
+            }
+            Ok(())
+        }
+
+        fn insert_secretariate(
+            &mut self,
+            secretariate_address: AccountId,
+            name: String,
+            company_id: u16,
+            rating: Option<u8>,
+        ) -> Result<()> {
+            let mut secretariate_info = MemberInfo {
+                name,
+                company_id,
+                designation: Designation::Secretariate,
+                rating,
+                skills: Vec::new(),
+            };
+            if let Some(info) = self.org_members.get(secretariate_address) {
+                if info.designation == Designation::Secretariate {
+                    return Err(Error::AlreadySecretariate);
+                } else if info.designation == Designation::Owner {
+                    return Err(Error::CannotChangeOwner);
+                } else {
+                    secretariate_info.skills = info.skills.clone();
                     self.org_members
                         .insert(secretariate_address, &secretariate_info);
-                    self.member_count += 1;
+                    self.member_weight = self
+                        .member_weight
+                        .saturating_sub(Self::rating_weight(info.rating))
+                        + Self::rating_weight(rating);
                     self.secretariate_count += 1;
+                    self.secretariate_weight += Self::rating_weight(rating);
                     self.secretariate.push(secretariate_address);
+                    EmitEvent::<Org>::emit_event(self.env(), DesignationChanged {
+                        member: secretariate_address,
+                        old_designation: info.designation,
+                        new_designation: Designation::Secretariate,
+                    });
                 }
             } else {
-                return Err(Error::UnAuthorized);
+                self.org_members
+                    .insert(secretariate_address, &secretariate_info);
+                self.member_list.push(secretariate_address);
+                self.member_count += 1;
+                self.member_weight += Self::rating_weight(rating);
+                self.secretariate_count += 1;
+                self.secretariate_weight += Self::rating_weight(rating);
+                self.secretariate.push(secretariate_address);
+                EmitEvent::<Org>::emit_event(self.env(), MemberAdded {
+                    member: secretariate_address,
+                    designation: Designation::Secretariate,
+                });
+            }
+            Ok(())
+        }
+
+        fn delete_member(&mut self, member_address: AccountId) -> Result<()> {
+            if let Some(info) = self.org_members.get(member_address) {
+                if info.designation == Designation::Owner {
+                    return Err(Error::CannotChangeOwner);
+                }
+                let weight = Self::rating_weight(info.rating);
+                if info.designation == Designation::Secretariate {
+                    self.drop_from_secretariate(member_address, weight);
+                }
+                self.org_members.remove(member_address);
+                self.member_list.retain(|member| *member != member_address);
+                self.member_count -= 1;
+                self.member_weight = self.member_weight.saturating_sub(weight);
+                EmitEvent::<Org>::emit_event(self.env(), MemberRemoved {
+                    member: member_address,
+                });
+            } else {
+                return Err(Error::MemberNotFound);
             }
             Ok(())
         }
 
+        fn demote_secretariate(&mut self, secretariate_address: AccountId) -> Result<()> {
+            if let Some(info) = self.org_members.get(secretariate_address) {
+                if info.designation != Designation::Secretariate {
+                    return Err(Error::NotASecretariate);
+                }
+                self.update_designation(secretariate_address, info, Designation::Member)
+            } else {
+                return Err(Error::MemberNotFound);
+            }
+        }
+
+        // keeps secretariate list and count in sync with the new designation
+        fn update_designation(
+            &mut self,
+            member_address: AccountId,
+            mut info: MemberInfo,
+            designation: Designation,
+        ) -> Result<()> {
+            if info.designation == Designation::Owner || designation == Designation::Owner {
+                return Err(Error::CannotChangeOwner);
+            }
+            if info.designation == designation {
+                return Ok(());
+            }
+            let weight = Self::rating_weight(info.rating);
+            if info.designation == Designation::Secretariate {
+                self.drop_from_secretariate(member_address, weight);
+            }
+            if designation == Designation::Secretariate {
+                self.secretariate_count += 1;
+                self.secretariate_weight += weight;
+                self.secretariate.push(member_address);
+            }
+            EmitEvent::<Org>::emit_event(self.env(), DesignationChanged {
+                member: member_address,
+                old_designation: info.designation,
+                new_designation: designation.clone(),
+            });
+            info.designation = designation;
+            self.org_members.insert(member_address, &info);
+            Ok(())
+        }
+
+        fn drop_from_secretariate(&mut self, secretariate_address: AccountId, weight: u64) {
+            if let Some(index) = self
+                .secretariate
+                .iter()
+                .position(|id| *id == secretariate_address)
+            {
+                self.secretariate.remove(index);
+                self.secretariate_count -= 1;
+                self.secretariate_weight = self.secretariate_weight.saturating_sub(weight);
+            }
+        }
+
+        // every member gets atleast one vote, rated members vote with their rating
+        fn rating_weight(rating: Option<u8>) -> u64 {
+            match rating {
+                Some(rating) if rating > 0 => rating as u64,
+                _ => 1,
+            }
+        }
+
         #[ink(message)]
         pub fn create_project(
             &mut self,
@@ -197,6 +567,7 @@ mod org {
             fund_allocated: u128, // store it in project
             strength: u64,
             proposer: AccountId,
+            voting_mode: VotingMode, // project proposals follow voting mode of the organisation
         ) -> Result<u32> {
             // caller should be proposal contract
             let creator = self.env().caller();
@@ -220,7 +591,7 @@ mod org {
                     };
 
                     let mut project_instance =
-                        ProjectRef::new(id, business_idea, creator, fund_allocated, strength,proposal_address)
+                        ProjectRef::new(id, business_idea, proposer, fund_allocated, strength,proposal_address)
                             .endowment(fund_allocated)
                             .code_hash(self.project_codehash)
                             .salt_bytes(salt)
@@ -228,7 +599,7 @@ mod org {
                     let project_address = project_instance.show_address();
 
                     let pprosal_endowment: u128 = 0;
-                    let project_proposal_instance = PproposalRef::new(project_address)
+                    let project_proposal_instance = PproposalRef::new(project_address, voting_mode)
                         .endowment(pprosal_endowment)
                         .code_hash(self.project_proposal_codehash)
                         .salt_bytes(salt)
@@ -239,7 +610,14 @@ mod org {
 
                     self.projects_manager.insert(project_address, &proposer);
                     self.project_list_by_id.insert(id, &project_address);
+                    self.project_status.insert(id, &ProjectStatus::Recruiting);
                     self.next_project_id += 1;
+                    EmitEvent::<Org>::emit_event(self.env(), ProjectCreated {
+                        project_id: id,
+                        project_address,
+                        manager: proposer,
+                        fund_allocated,
+                    });
 
                     Ok(id)
                 } else {
@@ -267,10 +645,12 @@ mod org {
                         ink::env::call::FromAccountId::from_account_id(address);
                     if let Some(outsider_info) = outsider {
                         // Handle error
+                        let skills = self.validate_skills(outsider_info.skills)?;
                         let response = project_instance.try_update_interested_list(
                             outsider_info.account_address,
                             outsider_info.name,
                             resume_url,
+                            skills,
                         );
                         match response {
                             Ok(()) => return Ok(()),
@@ -278,7 +658,7 @@ mod org {
                         }
                     } else {
                         let response =
-                            project_instance.try_update_interested_list(caller, info.name, resume_url);
+                            project_instance.try_update_interested_list(caller, info.name, resume_url, info.skills);
                         match response {
                             Ok(()) => return Ok(()),
                             Err(er) => return Err(er),
@@ -305,7 +685,14 @@ mod org {
                     }
                     let transfer_response = self.env().transfer(addr, amount);
                     match transfer_response {
-                        Ok(_) => return Ok(()),
+                        Ok(_) => {
+                            EmitEvent::<Org>::emit_event(self.env(), FundTransferred {
+                                project_id: id,
+                                project_address: addr,
+                                amount,
+                            });
+                            return Ok(());
+                        }
                         _ => return Err(Error::CannotTransferFund),
                     }
                 } else {
@@ -316,6 +703,190 @@ mod org {
             }
         }
 
+        // called by project when its members voted for a new manager
+        #[ink(message)]
+        pub fn record_project_manager(&mut self, project_id: u32, manager: AccountId) -> Result<()> {
+            let project_address = self.project_list_by_id.get(project_id);
+            if let Some(addr) = project_address {
+                if self.env().caller() != addr {
+                    return Err(Error::UnAuthorized);
+                }
+                self.projects_manager.insert(addr, &manager);
+                Ok(())
+            } else {
+                return Err(Error::ProjectNotFound);
+            }
+        }
+
+        // only being called when proposal passed for overriding manager of a project
+        #[ink(message)]
+        pub fn change_manager_by_proposal(&mut self, project_id: u32, manager: AccountId) -> Result<()> {
+            if Some(self.env().caller()) != self.proposal_address {
+                return Err(Error::UnAuthorized);
+            }
+            if self.org_members.get(manager).is_none() {
+                return Err(Error::MemberNotFound);
+            }
+            let project_address = self.project_list_by_id.get(project_id);
+            if let Some(addr) = project_address {
+                let mut project_instance: ProjectRef =
+                    ink::env::call::FromAccountId::from_account_id(addr);
+                project_instance.change_manager(manager)?;
+                self.projects_manager.insert(addr, &manager);
+                Ok(())
+            } else {
+                return Err(Error::ProjectNotFound);
+            }
+        }
+
+        // called by project whenever its status changes through project proposal
+        #[ink(message)]
+        pub fn record_project_status(&mut self, project_id: u32, status: ProjectStatus) -> Result<()> {
+            let project_address = self.project_list_by_id.get(project_id);
+            if let Some(addr) = project_address {
+                if self.env().caller() != addr {
+                    return Err(Error::UnAuthorized);
+                }
+                self.update_project_status(project_id, status);
+                Ok(())
+            } else {
+                return Err(Error::ProjectNotFound);
+            }
+        }
+
+        #[ink(message)]
+        pub fn add_skill_tag(&mut self, name: String) -> Result<u16> {
+            if self.env().caller() != self.org_owner {
+                return Err(Error::UnAuthorized);
+            }
+            let id = self.next_skill_tag_id;
+            self.skill_tags.insert(id, &name);
+            self.next_skill_tag_id += 1;
+            Ok(id)
+        }
+
+        // member declares its own skills
+        #[ink(message)]
+        pub fn update_member_skills(&mut self, skills: Vec<u16>) -> Result<()> {
+            let caller = self.env().caller();
+            let member_info = self.org_members.get(caller);
+            if let Some(mut info) = member_info {
+                info.skills = self.validate_skills(skills)?;
+                self.org_members.insert(caller, &info);
+                Ok(())
+            } else {
+                return Err(Error::MemberNotFound);
+            }
+        }
+
+        fn validate_skills(&self, skills: Vec<u16>) -> Result<Vec<u16>> {
+            let skills = normalize_skills(skills)?;
+            self.check_skill_tags(skills.clone())?;
+            Ok(skills)
+        }
+
+        // project proposals check required skills of tasks against the registry
+        #[ink(message)]
+        pub fn check_skill_tags(&self, skills: Vec<u16>) -> Result<()> {
+            if skills.iter().any(|id| !self.skill_tags.contains(id)) {
+                return Err(Error::SkillTagNotFound);
+            }
+            Ok(())
+        }
+
+        // called by project whenever a task of the member is rated, outsiders are not tracked
+        #[ink(message)]
+        pub fn record_member_rating(
+            &mut self,
+            project_id: u32,
+            member_address: AccountId,
+            task_id: u16,
+            rating: u8,
+        ) -> Result<()> {
+            if self.project_list_by_id.get(project_id) != Some(self.env().caller()) {
+                return Err(Error::UnAuthorized);
+            }
+            let member_info = self.org_members.get(member_address);
+            if let Some(mut info) = member_info {
+                let mut ratings = self.task_ratings.get(member_address).unwrap_or_default();
+                ratings.push(TaskRating {
+                    project_id,
+                    task_id,
+                    rating,
+                });
+                self.task_ratings.insert(member_address, &ratings);
+
+                let mut reputation = self.reputation.get(member_address).unwrap_or_default();
+                reputation.rating_sum += rating as u64;
+                reputation.rated_tasks += 1;
+                self.reputation.insert(member_address, &reputation);
+
+                // vote weight of the member follows its reputation
+                let old_weight = Self::rating_weight(info.rating);
+                info.rating = Some((reputation.rating_sum / reputation.rated_tasks) as u8);
+                let new_weight = Self::rating_weight(info.rating);
+                self.member_weight = self.member_weight - old_weight + new_weight;
+                if info.designation == Designation::Secretariate {
+                    self.secretariate_weight = self.secretariate_weight - old_weight + new_weight;
+                }
+                self.org_members.insert(member_address, &info);
+                EmitEvent::<Org>::emit_event(self.env(), ReputationUpdated {
+                    member: member_address,
+                    project_id,
+                    rating,
+                });
+            }
+            Ok(())
+        }
+
+        // only being called when proposal passed for completing or cancelling a project
+        #[ink(message)]
+        pub fn close_project_by_proposal(&mut self, project_id: u32, status: ProjectStatus) -> Result<()> {
+            if Some(self.env().caller()) != self.proposal_address {
+                return Err(Error::UnAuthorized);
+            }
+            if status != ProjectStatus::Completed && status != ProjectStatus::Cancelled {
+                return Err(Error::InvalidStatusTransition);
+            }
+            let project_address = self.project_list_by_id.get(project_id);
+            if let Some(addr) = project_address {
+                let mut project_instance: ProjectRef =
+                    ink::env::call::FromAccountId::from_account_id(addr);
+                // remaining project fund comes back to organisation
+                project_instance.update_project_status(status)?;
+                self.update_project_status(project_id, status);
+                Ok(())
+            } else {
+                return Err(Error::ProjectNotFound);
+            }
+        }
+
+        fn update_project_status(&mut self, project_id: u32, status: ProjectStatus) {
+            self.project_status.insert(project_id, &status);
+            EmitEvent::<Org>::emit_event(self.env(), ProjectStatusRecorded { project_id, status });
+        }
+
+        // only being called when proposal passed for rewarding member with governance token
+        #[ink(message)]
+        pub fn mint_token_by_proposal(&mut self, member_address: AccountId, amount: u128) -> Result<()> {
+            if Some(self.env().caller()) != self.proposal_address {
+                return Err(Error::UnAuthorized);
+            }
+            if self.org_members.get(member_address).is_none() {
+                return Err(Error::MemberNotFound);
+            }
+            if let Some(token_address) = self.token_address {
+                let mut token_instance: OrgTokenRef =
+                    ink::env::call::FromAccountId::from_account_id(token_address);
+                match token_instance.mint(member_address, amount) {
+                    Ok(()) => Ok(()),
+                    Err(_) => return Err(Error::CannotMintToken),
+                }
+            } else {
+                return Err(Error::TokenNotFound);
+            }
+        }
+
         // QUERIES  QUERIES QUERIES QUERIES QUERIES QUERIES  QUERIES QUERIES QUERIES QUERIES
         // QUERIES  QUERIES QUERIES QUERIES QUERIES QUERIES  QUERIES QUERIES QUERIES QUERIES
         // QUERIES  QUERIES QUERIES QUERIES QUERIES QUERIES  QUERIES QUERIES QUERIES QUERIES
@@ -357,6 +928,28 @@ mod org {
             self.next_project_id - 1
         }
         #[ink(message)]
+        pub fn get_project_manager(&self, id: u32) -> Result<AccountId> {
+            let project_address = self.project_list_by_id.get(id).ok_or(Error::ProjectNotFound)?;
+            self.projects_manager
+                .get(project_address)
+                .ok_or(Error::ProjectNotFound)
+        }
+        #[ink(message)]
+        pub fn get_project_status(&self, id: u32) -> Result<ProjectStatus> {
+            let project_status = self.project_status.get(id);
+            if let Some(status) = project_status {
+                Ok(status)
+            } else {
+                return Err(Error::ProjectNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn total_projects_by_status(&self, status: ProjectStatus) -> u32 {
+            (1..self.next_project_id)
+                .filter(|id| self.project_status.get(id) == Some(status))
+                .count() as u32
+        }
+        #[ink(message)]
         pub fn total_secretariate(&self) -> u64 {
             self.secretariate_count.clone()
         }
@@ -365,6 +958,60 @@ mod org {
             self.member_count.clone()
         }
         #[ink(message)]
+        pub fn total_member_weight(&self) -> u64 {
+            self.member_weight
+        }
+        #[ink(message)]
+        pub fn total_secretariate_weight(&self) -> u64 {
+            self.secretariate_weight
+        }
+        #[ink(message)]
+        pub fn get_vote_weight(&self, member_address: AccountId) -> Result<u64> {
+            let member_info = self.org_members.get(member_address);
+            if let Some(info) = member_info {
+                Ok(Self::rating_weight(info.rating))
+            } else {
+                return Err(Error::MemberNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn get_skill_tag(&self, id: u16) -> Option<String> {
+            self.skill_tags.get(id)
+        }
+        #[ink(message)]
+        pub fn total_skill_tags(&self) -> u16 {
+            self.next_skill_tag_id - 1
+        }
+        #[ink(message)]
+        pub fn get_member_project_ratings(&self, member_address: AccountId, project_id: u32) -> Vec<TaskRating> {
+            self.task_ratings
+                .get(member_address)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|task_rating| task_rating.project_id == project_id)
+                .collect()
+        }
+        /// projects in which member has a rated task
+        #[ink(message)]
+        pub fn get_member_projects(&self, member_address: AccountId) -> Vec<u32> {
+            let mut projects: Vec<u32> = Vec::new();
+            for task_rating in self.task_ratings.get(member_address).unwrap_or_default() {
+                if !projects.contains(&task_rating.project_id) {
+                    projects.push(task_rating.project_id);
+                }
+            }
+            projects
+        }
+        /// average rating over all rated project tasks of the member
+        #[ink(message)]
+        pub fn get_member_reputation(&self, member_address: AccountId) -> Option<u8> {
+            let reputation = self.reputation.get(member_address)?;
+            reputation
+                .rating_sum
+                .checked_div(reputation.rated_tasks)
+                .map(|rating| rating as u8)
+        }
+        #[ink(message)]
         pub fn get_member_info(&self, member_address: AccountId) -> Result<MemberInfo> {
             let member_info = self.org_members.get(member_address);
             if let Some(info) = member_info {
@@ -374,6 +1021,10 @@ mod org {
             }
         }
         #[ink(message)]
+        pub fn get_org_owner(&self) -> AccountId {
+            self.org_owner
+        }
+        #[ink(message)]
         pub fn get_org_owner_info(&self) -> MemberInfo {
             self.org_members.get(self.org_owner).unwrap()
         }
@@ -381,5 +1032,70 @@ mod org {
         pub fn get_org_name(&self) -> String {
             self.org_name.clone()
         }
+        #[ink(message)]
+        pub fn get_token_address(&self) -> Option<AccountId> {
+            self.token_address
+        }
+        #[ink(message)]
+        pub fn get_token_balance(&self, address: AccountId) -> u128 {
+            if let Some(token_address) = self.token_address {
+                let token_instance: OrgTokenRef =
+                    ink::env::call::FromAccountId::from_account_id(token_address);
+                token_instance.balance_of(address)
+            } else {
+                0
+            }
+        }
+        #[ink(message)]
+        pub fn total_token_supply(&self) -> u128 {
+            if let Some(token_address) = self.token_address {
+                let token_instance: OrgTokenRef =
+                    ink::env::call::FromAccountId::from_account_id(token_address);
+                token_instance.total_supply()
+            } else {
+                0
+            }
+        }
+        /// governance token balance in whole tokens, used as vote weight
+        #[ink(message)]
+        pub fn get_token_vote_weight(&self, address: AccountId) -> u64 {
+            self.token_vote_weight(&[address], None)
+        }
+        /// vote weight from the balance held at the start of the given block
+        #[ink(message)]
+        pub fn get_token_vote_weight_at(&self, address: AccountId, block: BlockNumber) -> u64 {
+            self.token_vote_weight(&[address], Some(block))
+        }
+        /// tokens held by non members can't vote, so only member balances are counted
+        #[ink(message)]
+        pub fn total_token_vote_weight(&self) -> u64 {
+            self.token_vote_weight(&self.member_list, None)
+        }
+        #[ink(message)]
+        pub fn total_token_vote_weight_at(&self, block: BlockNumber, secretariate: bool) -> u64 {
+            if secretariate {
+                self.token_vote_weight(&self.secretariate, Some(block))
+            } else {
+                self.token_vote_weight(&self.member_list, Some(block))
+            }
+        }
+
+        // sum of whole tokens held by the accounts, currently or at the start of block
+        fn token_vote_weight(&self, accounts: &[AccountId], block: Option<BlockNumber>) -> u64 {
+            if let Some(token_address) = self.token_address {
+                let token_instance: OrgTokenRef =
+                    ink::env::call::FromAccountId::from_account_id(token_address);
+                let unit = 10u128.saturating_pow(token_instance.token_decimals() as u32);
+                accounts.iter().fold(0u64, |total, account| {
+                    let balance = match block {
+                        Some(block) => token_instance.balance_of_at(*account, block),
+                        None => token_instance.balance_of(*account),
+                    };
+                    total.saturating_add(u64::try_from(balance / unit).unwrap_or(u64::MAX))
+                })
+            } else {
+                0
+            }
+        }
     }
 }
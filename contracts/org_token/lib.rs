@@ -0,0 +1,491 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use self::org_token::{OrgToken, OrgTokenRef};
+
+use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22Error {
+    /// Custom error type for cases if writer of traits added own restrictions
+    Custom(String),
+    /// Returned if not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Returned if not enough allowance to fulfill a request is available.
+    InsufficientAllowance,
+    /// Returned if recipient's address is zero.
+    ZeroRecipientAddress,
+    /// Returned if sender's address is zero.
+    ZeroSenderAddress,
+    /// Returned if safe transfer check fails
+    SafeTransferCheckFailed(String),
+}
+
+/// PSP22 fungible token standard, selectors are derived from `PSP22::<message>`
+#[ink::trait_definition]
+pub trait PSP22 {
+    #[ink(message)]
+    fn total_supply(&self) -> u128;
+
+    #[ink(message)]
+    fn balance_of(&self, owner: AccountId) -> u128;
+
+    #[ink(message)]
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> u128;
+
+    #[ink(message)]
+    fn transfer(&mut self, to: AccountId, value: u128, data: Vec<u8>) -> Result<(), PSP22Error>;
+
+    #[ink(message)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: u128,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    #[ink(message)]
+    fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error>;
+
+    #[ink(message)]
+    fn increase_allowance(&mut self, spender: AccountId, delta_value: u128) -> Result<(), PSP22Error>;
+
+    #[ink(message)]
+    fn decrease_allowance(&mut self, spender: AccountId, delta_value: u128) -> Result<(), PSP22Error>;
+}
+
+#[ink::trait_definition]
+pub trait PSP22Metadata {
+    #[ink(message)]
+    fn token_name(&self) -> Option<String>;
+
+    #[ink(message)]
+    fn token_symbol(&self) -> Option<String>;
+
+    #[ink(message)]
+    fn token_decimals(&self) -> u8;
+}
+
+/// governance token of the organisation. Organisation contract instantiates it and is the only minter
+#[ink::contract]
+pub mod org_token {
+
+    use crate::{PSP22Error, PSP22Metadata, PSP22};
+    use ink::codegen::EmitEvent;
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+    }
+
+    /// balance of an account from the given block on
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Checkpoint {
+        block: BlockNumber,
+        balance: Balance,
+    }
+
+    #[ink(storage)]
+    pub struct OrgToken {
+        total_supply: Balance,
+        balances: Mapping<AccountId, Balance>,
+        /// (owner, spender) -> allowance
+        allowances: Mapping<(AccountId, AccountId), Balance>,
+        /// (owner, index) -> balance checkpoint, lets votes use balances from before a proposal was created
+        checkpoints: Mapping<(AccountId, u32), Checkpoint>,
+        /// number of checkpoints stored for each account
+        checkpoint_count: Mapping<AccountId, u32>,
+        name: Option<String>,
+        symbol: Option<String>,
+        decimals: u8,
+        /// organisation contract which instantiated the token
+        minter: AccountId,
+    }
+
+    impl OrgToken {
+        #[ink(constructor)]
+        pub fn new(name: Option<String>, symbol: Option<String>, decimals: u8) -> Self {
+            Self {
+                total_supply: 0,
+                balances: Mapping::new(),
+                allowances: Mapping::new(),
+                checkpoints: Mapping::new(),
+                checkpoint_count: Mapping::new(),
+                name,
+                symbol,
+                decimals,
+                minter: Self::env().caller(),
+            }
+        }
+
+        // called by organisation to reward members
+        #[ink(message)]
+        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            if self.env().caller() != self.minter {
+                return Err(PSP22Error::Custom(String::from("UnAuthorized")));
+            }
+            let overflow = || PSP22Error::Custom(String::from("Overflow"));
+            let balance = self.balances.get(to).unwrap_or(0);
+            let new_balance = balance.checked_add(value).ok_or_else(overflow)?;
+            self.total_supply = self.total_supply.checked_add(value).ok_or_else(overflow)?;
+            self.set_balance(to, new_balance);
+            EmitEvent::<OrgToken>::emit_event(self.env(), Transfer {
+                from: None,
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn show_address(&self) -> AccountId {
+            self.env().account_id()
+        }
+
+        /// balance of owner at the start of the given block
+        #[ink(message)]
+        pub fn balance_of_at(&self, owner: AccountId, block: BlockNumber) -> Balance {
+            // checkpoints are ordered by block, find the first one at or after `block`
+            let mut low = 0;
+            let mut high = self.checkpoint_count.get(owner).unwrap_or(0);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                let checkpoint = self.checkpoints.get((owner, mid)).unwrap();
+                if checkpoint.block < block {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+            if low == 0 {
+                return 0;
+            }
+            self.checkpoints
+                .get((owner, low - 1))
+                .map_or(0, |checkpoint| checkpoint.balance)
+        }
+
+        fn set_balance(&mut self, owner: AccountId, balance: Balance) {
+            self.balances.insert(owner, &balance);
+            let block = self.env().block_number();
+            let count = self.checkpoint_count.get(owner).unwrap_or(0);
+            let index = match count.checked_sub(1) {
+                Some(last) if self.checkpoints.get((owner, last)).unwrap().block == block => last,
+                _ => {
+                    self.checkpoint_count.insert(owner, &(count + 1));
+                    count
+                }
+            };
+            self.checkpoints.insert((owner, index), &Checkpoint { block, balance });
+        }
+
+        fn transfer_from_to(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<(), PSP22Error> {
+            let from_balance = self.balances.get(from).unwrap_or(0);
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            self.set_balance(from, from_balance - value);
+            let to_balance = self.balances.get(to).unwrap_or(0);
+            self.set_balance(to, to_balance + value);
+            EmitEvent::<OrgToken>::emit_event(self.env(), Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+
+        fn set_allowance(&mut self, owner: AccountId, spender: AccountId, value: Balance) {
+            self.allowances.insert((owner, spender), &value);
+            EmitEvent::<OrgToken>::emit_event(self.env(), Approval {
+                owner,
+                spender,
+                value,
+            });
+        }
+    }
+
+    impl PSP22 for OrgToken {
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let from = self.env().caller();
+            self.transfer_from_to(from, to, value)
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let allowance = self.allowances.get((from, caller)).unwrap_or(0);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            self.transfer_from_to(from, to, value)?;
+            self.set_allowance(from, caller, allowance - value);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            self.set_allowance(owner, spender, value);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn increase_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: Balance,
+        ) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            let allowance = self.allowances.get((owner, spender)).unwrap_or(0);
+            self.set_allowance(owner, spender, allowance.saturating_add(delta_value));
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn decrease_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: Balance,
+        ) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            let allowance = self.allowances.get((owner, spender)).unwrap_or(0);
+            if allowance < delta_value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            self.set_allowance(owner, spender, allowance - delta_value);
+            Ok(())
+        }
+    }
+
+    impl PSP22Metadata for OrgToken {
+        #[ink(message)]
+        fn token_name(&self) -> Option<String> {
+            self.name.clone()
+        }
+
+        #[ink(message)]
+        fn token_symbol(&self) -> Option<String> {
+            self.symbol.clone()
+        }
+
+        #[ink(message)]
+        fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use ink::env::DefaultEnvironment;
+
+        fn accounts() -> test::DefaultAccounts<DefaultEnvironment> {
+            test::default_accounts::<DefaultEnvironment>()
+        }
+
+        fn set_caller(caller: AccountId) {
+            test::set_caller::<DefaultEnvironment>(caller);
+        }
+
+        fn next_block() {
+            test::advance_block::<DefaultEnvironment>();
+        }
+
+        fn token() -> OrgToken {
+            set_caller(accounts().alice);
+            OrgToken::new(Some(String::from("Org")), Some(String::from("ORG")), 0)
+        }
+
+        #[ink::test]
+        fn mint_by_minter_updates_balance_and_supply() {
+            let mut token = token();
+            assert_eq!(token.mint(accounts().bob, 100), Ok(()));
+            assert_eq!(token.balance_of(accounts().bob), 100);
+            assert_eq!(token.total_supply(), 100);
+        }
+
+        #[ink::test]
+        fn mint_by_other_account_is_unauthorized() {
+            let mut token = token();
+            set_caller(accounts().bob);
+            assert_eq!(
+                token.mint(accounts().bob, 100),
+                Err(PSP22Error::Custom(String::from("UnAuthorized")))
+            );
+            assert_eq!(token.total_supply(), 0);
+        }
+
+        #[ink::test]
+        fn mint_overflow_is_rejected() {
+            let mut token = token();
+            token.mint(accounts().bob, Balance::MAX).unwrap();
+            assert_eq!(
+                token.mint(accounts().charlie, 1),
+                Err(PSP22Error::Custom(String::from("Overflow")))
+            );
+            assert_eq!(token.balance_of(accounts().charlie), 0);
+        }
+
+        #[ink::test]
+        fn transfer_moves_balance() {
+            let mut token = token();
+            token.mint(accounts().bob, 100).unwrap();
+            set_caller(accounts().bob);
+            assert_eq!(token.transfer(accounts().charlie, 40, Vec::new()), Ok(()));
+            assert_eq!(token.balance_of(accounts().bob), 60);
+            assert_eq!(token.balance_of(accounts().charlie), 40);
+            assert_eq!(
+                token.transfer(accounts().charlie, 61, Vec::new()),
+                Err(PSP22Error::InsufficientBalance)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_from_spends_allowance() {
+            let mut token = token();
+            token.mint(accounts().bob, 100).unwrap();
+            set_caller(accounts().bob);
+            token.approve(accounts().charlie, 50).unwrap();
+            set_caller(accounts().charlie);
+            assert_eq!(
+                token.transfer_from(accounts().bob, accounts().django, 30, Vec::new()),
+                Ok(())
+            );
+            assert_eq!(token.allowance(accounts().bob, accounts().charlie), 20);
+            assert_eq!(token.balance_of(accounts().bob), 70);
+            assert_eq!(token.balance_of(accounts().django), 30);
+        }
+
+        #[ink::test]
+        fn transfer_from_above_allowance_fails() {
+            let mut token = token();
+            token.mint(accounts().bob, 100).unwrap();
+            set_caller(accounts().bob);
+            token.approve(accounts().charlie, 10).unwrap();
+            set_caller(accounts().charlie);
+            assert_eq!(
+                token.transfer_from(accounts().bob, accounts().django, 11, Vec::new()),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+            assert_eq!(token.allowance(accounts().bob, accounts().charlie), 10);
+            assert_eq!(token.balance_of(accounts().bob), 100);
+        }
+
+        #[ink::test]
+        fn transfer_from_above_balance_keeps_allowance() {
+            let mut token = token();
+            token.mint(accounts().bob, 10).unwrap();
+            set_caller(accounts().bob);
+            token.approve(accounts().charlie, 50).unwrap();
+            set_caller(accounts().charlie);
+            assert_eq!(
+                token.transfer_from(accounts().bob, accounts().django, 20, Vec::new()),
+                Err(PSP22Error::InsufficientBalance)
+            );
+            assert_eq!(token.allowance(accounts().bob, accounts().charlie), 50);
+        }
+
+        #[ink::test]
+        fn increase_and_decrease_allowance() {
+            let mut token = token();
+            set_caller(accounts().bob);
+            token.approve(accounts().charlie, 10).unwrap();
+            token.increase_allowance(accounts().charlie, 5).unwrap();
+            assert_eq!(token.allowance(accounts().bob, accounts().charlie), 15);
+            token.increase_allowance(accounts().charlie, Balance::MAX).unwrap();
+            assert_eq!(token.allowance(accounts().bob, accounts().charlie), Balance::MAX);
+            token.approve(accounts().charlie, 10).unwrap();
+            token.decrease_allowance(accounts().charlie, 4).unwrap();
+            assert_eq!(token.allowance(accounts().bob, accounts().charlie), 6);
+            assert_eq!(
+                token.decrease_allowance(accounts().charlie, 7),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+            assert_eq!(token.allowance(accounts().bob, accounts().charlie), 6);
+        }
+
+        #[ink::test]
+        fn balance_of_at_uses_balance_before_block() {
+            let mut token = token();
+            let start = ink::env::block_number::<DefaultEnvironment>();
+            token.mint(accounts().bob, 100).unwrap();
+            next_block();
+            set_caller(accounts().bob);
+            token.transfer(accounts().charlie, 30, Vec::new()).unwrap();
+            // several changes within one block keep a single checkpoint
+            token.transfer(accounts().charlie, 10, Vec::new()).unwrap();
+            next_block();
+            next_block();
+            token.transfer(accounts().charlie, 20, Vec::new()).unwrap();
+            next_block();
+
+            let bob = accounts().bob;
+            assert_eq!(token.balance_of_at(bob, start), 0);
+            assert_eq!(token.balance_of_at(bob, start + 1), 100);
+            assert_eq!(token.balance_of_at(bob, start + 2), 60);
+            assert_eq!(token.balance_of_at(bob, start + 3), 60);
+            assert_eq!(token.balance_of_at(bob, start + 4), 40);
+            assert_eq!(token.balance_of_at(bob, start + 100), 40);
+            assert_eq!(token.balance_of_at(accounts().charlie, start + 2), 40);
+            assert_eq!(token.balance_of_at(accounts().eve, start + 2), 0);
+            assert_eq!(token.checkpoint_count.get(bob), Some(3));
+        }
+    }
+}
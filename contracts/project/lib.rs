@@ -1,15 +1,27 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-pub use self::project::{AcceptedApproach, BusinessIdea, Project, ProjectRef, Task,Result,Error};
+pub use self::project::{
+    approach_escrow, normalize_skills, AcceptedApproach, BusinessIdea, DeadLine, DeadlinePolicy, Error, LatePenalty,
+    MemberRecommendation, Milestone, PeerRating, Project, ProjectRef, ProjectStatus,
+    RatingAggregation, Result, ReviewMode, ReviewOutcome, ReviewRecord, Task, TaskSchedule,
+    TaskStatus, VotingMode, MAX_SKILL_TAGS,
+};
 
 #[ink::contract]
 pub mod project {
 
+    use ink::codegen::EmitEvent;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::DefaultEnvironment;
+    use ink::prelude::collections::BTreeMap;
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
     use ink::storage::traits::StorageLayout;
     use ink::storage::Mapping;
 
+    /// upper bound on skill tags of a member or a task
+    pub const MAX_SKILL_TAGS: usize = 8;
+
     #[derive(Debug, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Error {
@@ -24,6 +36,11 @@ pub mod project {
         ProposalAddressAlreadyExist,
         ProjectNotFound,
         InsufficientFundInOrganisation,
+        CannotChangeOwner,
+        TokenAlreadyExist,
+        TokenNotFound,
+        CannotMintToken,
+        NotASecretariate,
         //project
         AlreadyApplied,
         ApproachAlreadyAccepted,
@@ -55,6 +72,59 @@ pub mod project {
         FundInfoNotFound,
         InvalidProjectInfo,
         ProjectIdNotFound,
+        MembershipInfoNotFound,
+        MintInfoNotFound,
+        VotingModeNotDefined,
+        InsufficientProjectFund,
+        NoRewardsToClaim,
+        InvalidMilestone,
+        DuplicateTaskId,
+        UnknownDependency,
+        TaskEndsBeforeStart,
+        TaskDependencyCycle,
+        RigidDeadline,
+        BeyondExtensionTolerance,
+        DeadlineNotPossible,
+        TaskNotInReview,
+        TooManySkillTags,
+        SkillTagNotFound,
+        PeerReviewRequired,
+        NotAReviewer,
+        InvalidReviewMode,
+        InvalidStatusTransition,
+        ProjectClosed,
+        ProjectStatusNotDefined,
+        CloseProjectInfoNotFound,
+        CannotDelegateToSelf,
+        DelegationCycle,
+        DelegationTooDeep,
+        NotDelegated,
+        RecruitmentClosed,
+        ApplicantNotFound,
+        ApplicationRejected,
+        ProjectAtFullStrength,
+        AlreadyShortlisted,
+        CannotReplaceWithSelf,
+        MemberRemovalInfoNotFound,
+        TaskChangeNotDefined,
+        ManagerInfoNotFound,
+        AlreadyManager,
+        CompetitionNotDefined,
+        SubmissionClosed,
+        SubmissionRunning,
+        TooManyApproaches,
+        InvalidBallot,
+        RankedBallotRequired,
+        EarlyResolutionInfoNotFound,
+        TimelockInfoNotFound,
+        TimelockActive,
+        TimelockExpired,
+        ProposalVetoed,
+        NoTokenVoteWeight,
+        TimelockTooLong,
+        AlreadySubmitted,
+        DuplicateMilestoneId,
+        NoIdleReviewers,
     }
     pub type Result<T> = core::result::Result<T, Error>;
 
@@ -68,6 +138,35 @@ pub mod project {
         Closed,
     }
 
+    #[derive(Debug, PartialEq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    /// lifecycle of the project, Completed and Cancelled are terminal
+    pub enum ProjectStatus {
+        Recruiting,
+        Planning,
+        Active,
+        Completed,
+        Cancelled,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    /// how votes are counted in organisation and project proposal contracts
+    pub enum VotingMode {
+        /// every voter has exactly one vote
+        Equal,
+        /// vote counts as the rating of the voter(org rating or task performance rating)
+        RatingWeighted,
+        /// vote counts as the governance token balance of the voter(organisation proposals only)
+        TokenWeighted,
+    }
+
     #[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
     #[cfg_attr(
         feature = "std",
@@ -80,6 +179,73 @@ pub mod project {
         Completed
     }
 
+    #[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum ReviewOutcome {
+        /// hash of the reason kept off chain
+        ChangesRequested(Hash),
+        Approved(u8),
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum RatingAggregation {
+        Median,
+        /// mean after dropping the highest and lowest rating(when atleast 3 ratings)
+        TrimmedMean,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum ReviewMode {
+        Manager,
+        /// reviewers are picked from members not assigned to the task on submission
+        Peer {
+            reviewers: u8,
+            /// ratings needed before the task rating is aggregated, atmost reviewers
+            min_reviews: u8,
+            aggregation: RatingAggregation,
+        },
+    }
+
+    #[derive(Debug, Clone, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+    pub struct PeerRating {
+        pub reviewer: AccountId,
+        pub rating: u8,
+    }
+
+    #[derive(Debug, Clone, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+    pub struct ReviewRecord {
+        pub round: u16,
+        pub reviewer: AccountId,
+        pub outcome: ReviewOutcome,
+        pub reviewed_at: Timestamp,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum DeadlinePolicy {
+        /// end time can never be extended
+        Rigid,
+        /// manager can extend upto the tolerance(in millisecond) past the planned end time,
+        /// longer extensions need an ExtendDeadLine proposal
+        Flexible(Timestamp),
+    }
+
     #[derive(Debug, Clone, scale::Decode, scale::Encode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
     pub struct BusinessIdea {
@@ -105,6 +271,21 @@ pub mod project {
         pub performance_rating: Option<u8>,
         pub details: Vec<String>,
         pub children_task: Vec<u16>,
+        /// kept in escrow and released to task members on completion
+        pub budget: u128,
+        pub deadline_policy: DeadlinePolicy,
+        /// skill tag ids from the registry of organisation
+        pub required_skills: Vec<u16>,
+    }
+
+    #[derive(Debug, Clone, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+    pub struct Milestone {
+        pub milestone_id: u16,
+        /// milestone is reached when all of these tasks are completed
+        pub task_ids: Vec<u16>,
+        /// kept in escrow and released to members of the milestone tasks
+        pub budget: u128,
     }
 
     #[derive(Debug, Clone, scale::Decode, scale::Encode)]
@@ -113,7 +294,46 @@ pub mod project {
     pub struct AcceptedApproach {
         pub project_start_date: Timestamp,
         pub project_completion_date: Timestamp,
-        pub tasks: Vec<Task>, // This task should contain the task in order -> task[j] should only depend on task[i] where i 0..j-1
+        pub tasks: Vec<Task>, // children_task and redundant start_after entries are worked out by project proposal
+        pub milestones: Vec<Milestone>,
+        /// tasks without any dependency, filled by project proposal after validating the task graph
+        pub root_tasks: Vec<u16>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+    /// applied on review for every started period a task is submitted after its end time
+    pub struct LatePenalty {
+        pub period: Timestamp,
+        /// rating points taken off per late period
+        pub rating_cut: u8,
+        /// percent of the task reward withheld per late period
+        pub reward_cut: u8,
+    }
+
+    #[derive(Debug, Clone, PartialEq, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct MemberRecommendation {
+        pub member: AccountId,
+        pub score: u64,
+        /// required skills of the task the member has
+        pub matched_skills: u32,
+        /// average performance rating over reviewed tasks(atleast 1)
+        pub average_rating: u64,
+        /// assigned tasks which are not yet completed
+        pub open_tasks: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    /// computed schedule of a task, slack is how much the task can slip without delaying the project
+    pub struct TaskSchedule {
+        pub task_id: u16,
+        pub earliest_start: Timestamp,
+        pub earliest_finish: Timestamp,
+        pub latest_start: Timestamp,
+        pub latest_finish: Timestamp,
+        pub slack: Timestamp,
     }
 
     #[derive(Debug, Clone, scale::Decode, scale::Encode)]
@@ -133,6 +353,186 @@ pub mod project {
         name: String,
         address: AccountId,
         resume_url: String,
+        /// skill tag ids from the registry of organisation
+        skills: Vec<u16>,
+    }
+
+    #[ink(event)]
+    pub struct ApproachAccepted {
+        #[ink(topic)]
+        project_id: u32,
+        project_start_date: Timestamp,
+        project_completion_date: Timestamp,
+        task_count: u32,
+    }
+
+    #[ink(event)]
+    pub struct ManagerChanged {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        old_manager: AccountId,
+        #[ink(topic)]
+        new_manager: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct TaskGraphUpdated {
+        #[ink(topic)]
+        project_id: u32,
+        task_count: u32,
+    }
+
+    #[ink(event)]
+    pub struct TaskAssigned {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct ProgressUpdated {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        #[ink(topic)]
+        member: AccountId,
+        status: TaskStatus,
+    }
+
+    #[ink(event)]
+    pub struct LatePenaltyApplied {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        late_periods: u32,
+        rating: u8,
+    }
+
+    #[ink(event)]
+    pub struct ChangesRequested {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        round: u16,
+        reason_hash: Hash,
+    }
+
+    #[ink(event)]
+    pub struct TaskReviewed {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        #[ink(topic)]
+        reviewer: AccountId,
+        rating: u8,
+    }
+
+    #[ink(event)]
+    pub struct TaskRewardDistributed {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct RewardsClaimed {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        member: AccountId,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct MilestoneReleased {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        milestone_id: u16,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct PeerReviewersRepicked {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        reviewers: Vec<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct ApplicantShortlisted {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        applicant: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct ApplicantRejected {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        applicant: AccountId,
+        reason_hash: Hash,
+    }
+
+    #[ink(event)]
+    pub struct MemberJoined {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct MemberRemoved {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        member: AccountId,
+        replacement: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct RecruitmentClosed {
+        #[ink(topic)]
+        project_id: u32,
+        member_count: u64,
+    }
+
+    #[ink(event)]
+    pub struct ProjectStatusChanged {
+        #[ink(topic)]
+        project_id: u32,
+        status: ProjectStatus,
+    }
+
+    #[ink(event)]
+    pub struct FundReturned {
+        #[ink(topic)]
+        project_id: u32,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct DeadlineExtended {
+        #[ink(topic)]
+        project_id: u32,
+        #[ink(topic)]
+        task_id: u16,
+        end_time: Timestamp,
     }
 
     #[ink(storage)]
@@ -140,7 +540,7 @@ pub mod project {
         ///unique id of the project
         id: u32,
         project_info: BusinessIdea,
-        /// manager who proposed business plan(add change function later)
+        /// manager who proposed business plan, changed by project proposal or organisation
         manager: AccountId,
         curr_member_count: u64,
         /// store list of address of all members accepted in the project
@@ -158,17 +558,53 @@ pub mod project {
         organisation_contract: AccountId,
         organisation_proposal_contract: AccountId,
         community_formation_status: CommunityFormationStatus,
+        status: ProjectStatus,
 
         // mamanger will check and let them in the project(Make it more appropriate)
         interested_members: Vec<AccountId>,
         interested_members_info: Mapping<AccountId, MemberInfo>,
+        shortlisted_members: Vec<AccountId>,
+        // rejected applicant -> hash of the reason kept off chain
+        rejected_applicants: Mapping<AccountId, Hash>,
 
         // approach: Option<AcceptedApproach>,
         deadline: Option<DeadLine>,
         task_by_id: Mapping<u16, Task>,
+        // end time of the task in accepted approach, extension tolerance is counted from here
+        planned_end_time: Mapping<u16, Timestamp>,
+        // duration of the task when it was planned, schedule keeps it fixed when parents are extended
+        planned_duration: Mapping<u16, Timestamp>,
+        /// ids of all the tasks of accepted approach
+        task_ids: Vec<u16>,
         root_tasks: Vec<u16>,
 
         review_tasks: Vec<u16>,
+        // time when the task was last submitted for review
+        submitted_at: Mapping<u16, Timestamp>,
+        late_penalty: Option<LatePenalty>,
+        // penalty in effect when the task was first assigned, later changes don't apply to the task
+        task_late_penalty: Mapping<u16, LatePenalty>,
+        // count of rounds a task was sent back for rework
+        review_rounds: Mapping<u16, u16>,
+        review_history: Mapping<u16, Vec<ReviewRecord>>,
+        review_mode: ReviewMode,
+        // peer reviewers picked for the current review round and ratings submitted by them
+        task_reviewers: Mapping<u16, Vec<AccountId>>,
+        peer_ratings: Mapping<u16, Vec<PeerRating>>,
+        /// fixed when the task is first assigned, so submitters can't steer who reviews them
+        review_seed: Mapping<u16, u64>,
+
+        /// task id -> reward budget, shared among task members on completion
+        task_budget: Mapping<u16, u128>,
+        /// part of total_fund_allocated committed to task budgets(including distributed rewards)
+        rewards_budgeted: u128,
+        /// member -> rewards earned but not yet claimed
+        pending_rewards: Mapping<AccountId, u128>,
+        total_pending_rewards: u128,
+        /// task and milestone budgets which are not released yet
+        escrow_balance: u128,
+        milestones: Mapping<u16, Milestone>,
+        milestone_ids: Vec<u16>,
 
         project_proposal_address: Option<AccountId>,
     }
@@ -200,12 +636,34 @@ pub mod project {
                 organisation_contract: Self::env().caller(),
                 organisation_proposal_contract: proposal_contract,
                 community_formation_status: CommunityFormationStatus::Running,
+                status: ProjectStatus::Recruiting,
                 interested_members: Vec::new(),
                 interested_members_info: Mapping::new(),
+                shortlisted_members: Vec::new(),
+                rejected_applicants: Mapping::new(),
                 deadline: None,
                 task_by_id: Mapping::new(),
+                planned_end_time: Mapping::new(),
+                planned_duration: Mapping::new(),
+                task_ids: Vec::new(),
                 root_tasks: Vec::new(),
                 review_tasks: Vec::new(),
+                submitted_at: Mapping::new(),
+                late_penalty: None,
+                task_late_penalty: Mapping::new(),
+                review_rounds: Mapping::new(),
+                review_history: Mapping::new(),
+                review_mode: ReviewMode::Manager,
+                task_reviewers: Mapping::new(),
+                peer_ratings: Mapping::new(),
+                review_seed: Mapping::new(),
+                task_budget: Mapping::new(),
+                rewards_budgeted: 0,
+                pending_rewards: Mapping::new(),
+                total_pending_rewards: 0,
+                escrow_balance: 0,
+                milestones: Mapping::new(),
+                milestone_ids: Vec::new(),
                 project_proposal_address: None,
             }
         }
@@ -227,6 +685,43 @@ pub mod project {
             }
         }
 
+        // project proposal(after members voted) or organisation(after org proposal) hands over the project
+        #[ink(message)]
+        pub fn change_manager(&mut self, new_manager: AccountId) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            let from_organisation = caller == self.organisation_contract;
+            if !from_organisation && Some(caller) != self.project_proposal_address {
+                return Err(Error::UnAuthorized);
+            }
+            if new_manager == self.manager {
+                return Err(Error::AlreadyManager);
+            }
+            let old_manager = self.manager;
+            self.manager = new_manager;
+            EmitEvent::<Project>::emit_event(self.env(), ManagerChanged {
+                project_id: self.id,
+                old_manager,
+                new_manager,
+            });
+            // organisation records the manager itself, calling it back would be a reentrant call
+            if !from_organisation {
+                build_call::<DefaultEnvironment>()
+                    .call(self.organisation_contract)
+                    .gas_limit(0)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                            "record_project_manager"
+                        )))
+                        .push_arg(self.id)
+                        .push_arg(new_manager),
+                    )
+                    .returns::<Result<()>>()
+                    .invoke()?;
+            }
+            Ok(())
+        }
+
         // called by organisation
         #[ink(message)]
         pub fn try_update_interested_list(
@@ -234,13 +729,22 @@ pub mod project {
             address: AccountId,
             name: String,
             resume_url: String,
+            skills: Vec<u16>,
         ) -> Result<()> {
+            self.ensure_not_closed()?;
             let caller = self.env().caller();
             if caller == self.organisation_contract {
+                if self.community_formation_status == CommunityFormationStatus::Closed {
+                    return Err(Error::RecruitmentClosed);
+                }
+                if self.rejected_applicants.contains(address) {
+                    return Err(Error::ApplicationRejected);
+                }
                 let member_info = MemberInfo {
                     name,
                     address,
                     resume_url,
+                    skills: normalize_skills(skills)?,
                 };
                 if let Some(_info) = self.interested_members_info.get(address) {
                     return Err(Error::AlreadyApplied);
@@ -259,27 +763,29 @@ pub mod project {
 
         #[ink(message)]
         pub fn try_add_member(&mut self, interested_member_address: AccountId) -> Result<()> {
+            self.ensure_not_closed()?;
             let caller = self.env().caller();
 
-            if caller == self.manager && self.curr_member_count < self.strength {
-                let member_info = self
-                    .interested_members_info
-                    .get(interested_member_address)
-                    .unwrap();
-
-                self.interested_members_info
-                    .remove(interested_member_address);
-
-                let index = self
-                    .interested_members
-                    .iter()
-                    .position(|id| *id == interested_member_address)
-                    .unwrap();
-                self.interested_members.remove(index);
+            if caller == self.manager {
+                if self.community_formation_status == CommunityFormationStatus::Closed {
+                    return Err(Error::RecruitmentClosed);
+                }
+                if self.curr_member_count >= self.strength {
+                    return Err(Error::ProjectAtFullStrength);
+                }
+                let member_info = self.take_applicant(interested_member_address)?;
 
                 self.member_list.push(interested_member_address);
                 self.members_info
                     .insert(interested_member_address, &member_info);
+                self.curr_member_count += 1;
+                EmitEvent::<Project>::emit_event(self.env(), MemberJoined {
+                    project_id: self.id,
+                    member: interested_member_address,
+                });
+                if self.curr_member_count >= self.strength {
+                    self.close_community_formation()?;
+                }
             } else {
                 return Err(Error::UnAuthorized);
             }
@@ -287,11 +793,158 @@ pub mod project {
             Ok(())
         }
 
+        #[ink(message)]
+        pub fn shortlist_applicant(&mut self, applicant: AccountId) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized);
+            }
+            if !self.interested_members_info.contains(applicant) {
+                return Err(Error::ApplicantNotFound);
+            }
+            if self.shortlisted_members.contains(&applicant) {
+                return Err(Error::AlreadyShortlisted);
+            }
+            self.shortlisted_members.push(applicant);
+            EmitEvent::<Project>::emit_event(self.env(), ApplicantShortlisted {
+                project_id: self.id,
+                applicant,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn reject_applicant(&mut self, applicant: AccountId, reason_hash: Hash) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized);
+            }
+            self.take_applicant(applicant)?;
+            self.rejected_applicants.insert(applicant, &reason_hash);
+            EmitEvent::<Project>::emit_event(self.env(), ApplicantRejected {
+                project_id: self.id,
+                applicant,
+                reason_hash,
+            });
+            Ok(())
+        }
+
+        // manager stops taking applications before the project reaches its strength
+        #[ink(message)]
+        pub fn close_recruitment(&mut self) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized);
+            }
+            if self.community_formation_status == CommunityFormationStatus::Closed {
+                return Err(Error::RecruitmentClosed);
+            }
+            self.close_community_formation()
+        }
+
+        /*
+            manager or project proposal(after members voted) removes a member
+            1. open tasks of the member are unassigned, and handed over to the replacement(if any)
+            2. completed tasks stay assigned so that ratings and pending rewards of the member are kept
+        */
+        #[ink(message)]
+        pub fn remove_project_member(&mut self, member: AccountId, replacement: Option<AccountId>) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if caller != self.manager && Some(caller) != self.project_proposal_address {
+                return Err(Error::UnAuthorized);
+            }
+            if !self.members_info.contains(member) {
+                return Err(Error::MemberNotFound);
+            }
+            if let Some(replacement) = replacement {
+                if replacement == member {
+                    return Err(Error::CannotReplaceWithSelf);
+                }
+                if !self.members_info.contains(replacement) {
+                    return Err(Error::MemberNotFound);
+                }
+            }
+
+            let assigned_tasks = self.members_assigned_task_list.get(member).unwrap_or_default();
+            let mut completed_tasks: Vec<u16> = Vec::new();
+            for task_id in assigned_tasks {
+                let task = self.task_by_id.get(task_id).unwrap();
+                if task.status == TaskStatus::Completed {
+                    completed_tasks.push(task_id);
+                    continue;
+                }
+                let mut members_in_task = self.members_in_task.get(task_id).unwrap_or_default();
+                members_in_task.retain(|id| *id != member);
+                self.members_in_task.insert(task_id, &members_in_task);
+                if let Some(replacement) = replacement {
+                    if !members_in_task.contains(&replacement) {
+                        self.assign_task(task_id, replacement);
+                    }
+                }
+            }
+            self.members_assigned_task_list.insert(member, &completed_tasks);
+
+            // pending peer reviews of the member are dropped
+            for task_id in self.review_tasks.clone() {
+                let mut reviewers = self.task_reviewers.get(task_id).unwrap_or_default();
+                if !reviewers.contains(&member) {
+                    continue;
+                }
+                reviewers.retain(|id| *id != member);
+                self.task_reviewers.insert(task_id, &reviewers);
+                let mut ratings = self.peer_ratings.get(task_id).unwrap_or_default();
+                ratings.retain(|peer_rating| peer_rating.reviewer != member);
+                self.peer_ratings.insert(task_id, &ratings);
+                self.finish_peer_review(self.task_by_id.get(task_id).unwrap())?;
+            }
+
+            self.member_list.retain(|id| *id != member);
+            self.members_info.remove(member);
+            self.curr_member_count -= 1;
+            EmitEvent::<Project>::emit_event(self.env(), MemberRemoved {
+                project_id: self.id,
+                member,
+                replacement,
+            });
+            Ok(())
+        }
+
+        // removes the applicant from interested and shortlisted lists
+        fn take_applicant(&mut self, applicant: AccountId) -> Result<MemberInfo> {
+            let member_info = self
+                .interested_members_info
+                .get(applicant)
+                .ok_or(Error::ApplicantNotFound)?;
+            self.interested_members_info.remove(applicant);
+            self.interested_members.retain(|id| *id != applicant);
+            self.shortlisted_members.retain(|id| *id != applicant);
+            Ok(member_info)
+        }
+
+        // project still recruiting moves on to planning once the community is formed
+        fn close_community_formation(&mut self) -> Result<()> {
+            if self.status == ProjectStatus::Recruiting {
+                self.change_status(ProjectStatus::Planning)?;
+                self.report_status_to_organisation()?;
+            }
+            self.community_formation_status = CommunityFormationStatus::Closed;
+            EmitEvent::<Project>::emit_event(self.env(), RecruitmentClosed {
+                project_id: self.id,
+                member_count: self.curr_member_count,
+            });
+            Ok(())
+        }
+
         
         // Discuss : how will the passed approach for project should be accepted.
         // Directly from proposal dao or manager will manually accept
         #[ink(message)]
         pub fn try_accept_proposed_approach(&mut self, approach: AcceptedApproach) -> Result<()> {
+            self.ensure_not_closed()?;
             let caller = self.env().caller();
             if let Some(_) = self.deadline {
                 return Err(Error::ApproachAlreadyAccepted);
@@ -303,14 +956,42 @@ pub mod project {
                     project_start_date: approach.project_start_date,
                     project_completion_date: approach.project_completion_date
                 };
+                let escrow = approach_escrow(&approach)?;
+                if escrow > self.get_unbudgeted_fund() {
+                    return Err(Error::InsufficientProjectFund);
+                }
                 self.deadline = Some(deadline);
 
+                let task_count = approach.tasks.len() as u32;
                 for task in approach.tasks {
                     self.task_by_id.insert(task.task_id, &task);
-                    if task.start_after.len() == 0 {
-                        self.root_tasks.push(task.task_id);
+                    self.planned_end_time.insert(task.task_id, &task.end_time);
+                    self.task_ids.push(task.task_id);
+                    if task.budget > 0 {
+                        self.task_budget.insert(task.task_id, &task.budget);
                     }
                 }
+                for task_id in self.task_ids.clone() {
+                    self.record_planned_duration(task_id);
+                }
+                self.root_tasks = approach.root_tasks;
+                for milestone in approach.milestones {
+                    self.milestones.insert(milestone.milestone_id, &milestone);
+                    self.milestone_ids.push(milestone.milestone_id);
+                }
+                self.rewards_budgeted += escrow;
+                self.escrow_balance += escrow;
+                EmitEvent::<Project>::emit_event(self.env(), ApproachAccepted {
+                    project_id: self.id,
+                    project_start_date: approach.project_start_date,
+                    project_completion_date: approach.project_completion_date,
+                    task_count,
+                });
+                // accepted approach puts the project into execution
+                if self.status != ProjectStatus::Active {
+                    self.change_status(ProjectStatus::Active)?;
+                    self.report_status_to_organisation()?;
+                }
             } else {
                 return Err(Error::UnAuthorized);
             }
@@ -321,33 +1002,66 @@ pub mod project {
         // one member many task
         #[ink(message)]
         pub fn try_assign_task_to_member(&mut self,task_id: u16, member_address: AccountId) -> Result<()> {
+            self.ensure_not_closed()?;
 
             let caller = self.env().caller();
 
             if caller != self.manager {
                 return Err(Error::UnAuthorized)
             }
-            let assigned_tasks = self.members_assigned_task_list.get(member_address);
-
-            if let Some(mut assigned_tasks) = assigned_tasks {
-                let already_assigned = assigned_tasks.iter().find(|id| **id == task_id);
-                if already_assigned.is_some() {
-                    return Err(Error::TaskAlreadyAssigned)
-                }
-                assigned_tasks.push(task_id);
-                self.members_assigned_task_list.insert(member_address,&assigned_tasks);
+            if self.task_by_id.get(task_id).is_none() {
+                return Err(Error::TaskNotFound)
+            }
+            // only project members can be assigned, they get a share of the task reward
+            if !self.members_info.contains(member_address) {
+                return Err(Error::MemberNotFound)
+            }
+            // first task of the member and first member of the task start with empty lists
+            let assigned_tasks = self
+                .members_assigned_task_list
+                .get(member_address)
+                .unwrap_or_default();
+
+            let already_assigned = assigned_tasks.iter().find(|id| **id == task_id);
+            if already_assigned.is_some() {
+                return Err(Error::TaskAlreadyAssigned)
+            }
+            self.assign_task(task_id, member_address);
 
-                let mut members_in_task = self.members_in_task.get(task_id).unwrap();
-                members_in_task.push(member_address);
-                self.members_in_task.insert(task_id,&members_in_task);
+            Ok(())
+        }
 
+        fn assign_task(&mut self, task_id: u16, member_address: AccountId) {
+            let mut assigned_tasks = self
+                .members_assigned_task_list
+                .get(member_address)
+                .unwrap_or_default();
+            assigned_tasks.push(task_id);
+            self.members_assigned_task_list.insert(member_address,&assigned_tasks);
+
+            let mut members_in_task = self.members_in_task.get(task_id).unwrap_or_default();
+            members_in_task.push(member_address);
+            self.members_in_task.insert(task_id,&members_in_task);
+            if !self.review_seed.contains(task_id) {
+                let seed = self
+                    .env()
+                    .block_timestamp()
+                    .wrapping_add(self.env().block_number() as u64);
+                self.review_seed.insert(task_id, &seed);
+                if let Some(penalty) = self.late_penalty.clone() {
+                    self.task_late_penalty.insert(task_id, &penalty);
+                }
             }
-            
-            Ok(())
+            EmitEvent::<Project>::emit_event(self.env(), TaskAssigned {
+                project_id: self.id,
+                task_id,
+                member: member_address,
+            });
         }
 
         #[ink(message)]
         pub fn try_update_task_progress(&mut self,task_id: u16, progress: u8) -> Result<()> {
+            self.ensure_not_closed()?;
 
             let caller = self.env().caller();
 
@@ -367,10 +1081,18 @@ pub mod project {
                         if progress >= 100 {
                             task.status = TaskStatus::InReview;
                             self.review_tasks.push(task_id);
+                            self.submitted_at.insert(task_id, &self.env().block_timestamp());
+                            self.pick_peer_reviewers(task_id);
                         }else{
                             task.status = TaskStatus::Running(progress);
                         }
                         self.task_by_id.insert(task_id, &task);
+                        EmitEvent::<Project>::emit_event(self.env(), ProgressUpdated {
+                            project_id: self.id,
+                            task_id,
+                            member: caller,
+                            status: task.status,
+                        });
                     }
                 }
             }else{
@@ -382,100 +1104,814 @@ pub mod project {
         // manager will review the task
         #[ink(message)]
         pub fn review_task_and_rate(&mut self,task_id: u16,rating: u8) -> Result<()> {
+            self.ensure_not_closed()?;
 
             let caller = self.env().caller();
             if caller != self.manager {
                 return Err(Error::UnAuthorized)
             }
-            let mut task = self.task_by_id.get(task_id).unwrap();
+            let task = self.task_by_id.get(task_id).unwrap();
             
             if task.status == TaskStatus::Completed {
                 return Err(Error::AlreadyReviewed)
             }
+            if !self.task_reviewers.get(task_id).unwrap_or_default().is_empty() {
+                return Err(Error::PeerReviewRequired)
+            }
 
             if task.status == TaskStatus::InReview {
-                task.performance_rating = Some(rating);
-                self.task_by_id.insert(task_id,&task);
-                task.status = TaskStatus::Completed;
+                self.complete_review(task, rating)?;
             }
 
             Ok(())
         }
 
-
-        // check curr task and all children tasks(and their children) accordingly. These are the possible voters for deadline extension
+        // peer reviewer rates the task, final rating is aggregated once enough reviewers rated
         #[ink(message)]
-        pub fn try_check_voters_for_deadline_extension(&self,task_id: u16, member: AccountId, check_task_only: bool) -> Result<bool> {
-            
-            let members = self.members_in_task.get(task_id);
-            let mut _is_possible = false;
+        pub fn submit_peer_review(&mut self, task_id: u16, rating: u8) -> Result<()> {
+            self.ensure_not_closed()?;
 
-            if let Some(mut members) = members {
-                if !check_task_only {
-                    let children_task = self.task_by_id.get(task_id).unwrap().children_task;
-    
-                    for child in children_task {
-                        if let Some(mut child_members) = self.members_in_task.get(child) {
-                            members.append(&mut child_members);
-                        }
-                    }
-                }
-                if members.iter().find(|x| **x == member).is_some() {
-                    Ok(true)
-                }else{
-                    Ok(false)
-                }
-            }else{
-                return Err(Error::TaskNotFound)
+            let caller = self.env().caller();
+            let task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            if task.status != TaskStatus::InReview {
+                return Err(Error::TaskNotInReview)
             }
+            let reviewers = self.task_reviewers.get(task_id).unwrap_or_default();
+            if !reviewers.contains(&caller) {
+                return Err(Error::NotAReviewer)
+            }
+            let mut ratings = self.peer_ratings.get(task_id).unwrap_or_default();
+            if ratings.iter().any(|peer_rating| peer_rating.reviewer == caller) {
+                return Err(Error::AlreadyReviewed)
+            }
+            ratings.push(PeerRating {
+                reviewer: caller,
+                rating,
+            });
+            self.peer_ratings.insert(task_id, &ratings);
+            self.finish_peer_review(task)
         }
-        
 
-        #[ink(message)]
-        pub fn is_possible_deadline(&self,task_id: u16, completion_time: Timestamp) -> Result<bool> {
-
-            let task = self.task_by_id.get(task_id);
-            let mut _is_possible = false;
-            if let Some(task) = task {
-                _is_possible = self.check_neighbour_deadline(task,completion_time);
-            }else{
-                return Err(Error::TaskNotFound)
+        fn finish_peer_review(&mut self, task: Task) -> Result<()> {
+            let reviewers = self.task_reviewers.get(task.task_id).unwrap_or_default();
+            let ratings = self.peer_ratings.get(task.task_id).unwrap_or_default();
+            let (min_reviews, aggregation) = match self.review_mode {
+                ReviewMode::Peer {
+                    min_reviews,
+                    aggregation,
+                    ..
+                } => (min_reviews as usize, aggregation),
+                // mode changed after reviewers were picked
+                ReviewMode::Manager => (reviewers.len(), RatingAggregation::Median),
+            };
+            // removed reviewers can leave less reviewers than needed ratings
+            let needed = min_reviews.min(reviewers.len());
+            if !reviewers.is_empty() && ratings.len() >= needed {
+                let mut ratings: Vec<u8> = ratings.iter().map(|peer_rating| peer_rating.rating).collect();
+                let rating = aggregate_ratings(&mut ratings, aggregation);
+                self.complete_review(task, rating)?;
             }
-
-            Ok(_is_possible)
+            Ok(())
         }
-        // query all the children nodes if endtime of the task is not crossing the end time of neighbours
-        // otherwise task extension is not possible,
-        fn check_neighbour_deadline(&self,task: Task,endtime: Timestamp) -> bool {
-            let children_task = task.children_task;
-            let mut is_possible = true;
-            for child in children_task {
-                let child_task = self.task_by_id.get(child).unwrap();
-                if child_task.end_time <= endtime {
-                    is_possible = false;
+
+        #[ink(message)]
+        pub fn set_review_mode(&mut self, review_mode: ReviewMode) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized)
+            }
+            if let ReviewMode::Peer {
+                reviewers,
+                min_reviews,
+                ..
+            } = review_mode
+            {
+                if min_reviews == 0 || min_reviews > reviewers {
+                    return Err(Error::InvalidReviewMode)
                 }
             }
-            is_possible
+            self.review_mode = review_mode;
+            Ok(())
         }
-        
-        #[ink(message)]
-        pub fn try_extend_task_deadline(&mut self,task_id: u16, completion_time: Timestamp) -> Result<()> {
 
-            let caller = self.env().caller();
-            // everything already checked in proposal
-            if Some(caller) == self.project_proposal_address {
-                let mut task = self.task_by_id.get(task_id).unwrap();
-                task.end_time = completion_time;
-                self.task_by_id.insert(task_id, &task);
-                self.build_deadline_graph(task_id);
+        // rotates over members not working on the task, when nobody is eligible manager reviews it
+        fn pick_peer_reviewers(&mut self, task_id: u16) {
+            let count = match self.review_mode {
+                ReviewMode::Peer { reviewers, .. } => reviewers as usize,
+                ReviewMode::Manager => return,
+            };
+            let reviewers = self.rotate_reviewers(task_id, &[], count);
+            if reviewers.is_empty() {
+                return;
+            }
+            self.task_reviewers.insert(task_id, &reviewers);
+            self.peer_ratings.remove(task_id);
+        }
 
-            } else {
-                return Err(Error::UnAuthorized);
+        // upto count members who neither work on the task nor are in excluded, starting at the seed of the task
+        fn rotate_reviewers(&self, task_id: u16, excluded: &[AccountId], count: usize) -> Vec<AccountId> {
+            let members_in_task = self.members_in_task.get(task_id).unwrap_or_default();
+            let eligible: Vec<AccountId> = self
+                .member_list
+                .iter()
+                .filter(|member| !members_in_task.contains(member) && !excluded.contains(member))
+                .copied()
+                .collect();
+            if eligible.is_empty() {
+                return Vec::new();
             }
-            Ok(())
+            let offset = self.review_seed.get(task_id).unwrap_or(task_id as u64) as usize;
+            (0..count.min(eligible.len()))
+                .map(|i| eligible[offset.wrapping_add(i) % eligible.len()])
+                .collect()
         }
 
-        /*
+        // manager replaces peer reviewers who have not rated yet(e.g. inactive ones),
+        // they are dropped when nobody else is eligible
+        #[ink(message)]
+        pub fn repick_peer_reviewers(&mut self, task_id: u16) -> Result<()> {
+            self.ensure_not_closed()?;
+
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized)
+            }
+            let task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            if task.status != TaskStatus::InReview {
+                return Err(Error::TaskNotInReview)
+            }
+            let reviewers = self.task_reviewers.get(task_id).unwrap_or_default();
+            let ratings = self.peer_ratings.get(task_id).unwrap_or_default();
+            let (rated, idle): (Vec<AccountId>, Vec<AccountId>) = reviewers
+                .iter()
+                .partition(|reviewer| ratings.iter().any(|peer_rating| peer_rating.reviewer == **reviewer));
+            if idle.is_empty() {
+                return Err(Error::NoIdleReviewers)
+            }
+            // move the rotation on, so the same idle reviewers are not picked again
+            let seed = self.review_seed.get(task_id).unwrap_or(task_id as u64);
+            self.review_seed
+                .insert(task_id, &seed.wrapping_add(reviewers.len() as u64));
+            let mut new_reviewers = self.rotate_reviewers(task_id, &reviewers, idle.len());
+            new_reviewers.extend(rated);
+            self.task_reviewers.insert(task_id, &new_reviewers);
+            EmitEvent::<Project>::emit_event(self.env(), PeerReviewersRepicked {
+                project_id: self.id,
+                task_id,
+                reviewers: new_reviewers,
+            });
+            // with reviewers dropped the ratings in may already be enough
+            self.finish_peer_review(task)
+        }
+
+        fn complete_review(&mut self, mut task: Task, rating: u8) -> Result<()> {
+            let task_id = task.task_id;
+            let late_periods = self.late_periods(&task);
+            let rating = if let Some(penalty) = self.task_late_penalty.get(task_id) {
+                let cut = (penalty.rating_cut as u32).saturating_mul(late_periods);
+                rating.saturating_sub(cut.min(u8::MAX as u32) as u8)
+            } else {
+                rating
+            };
+            if late_periods > 0 {
+                EmitEvent::<Project>::emit_event(self.env(), LatePenaltyApplied {
+                    project_id: self.id,
+                    task_id,
+                    late_periods,
+                    rating,
+                });
+            }
+            task.performance_rating = Some(rating);
+            task.status = TaskStatus::Completed;
+            self.task_by_id.insert(task_id,&task);
+            self.remove_from_review(task_id, ReviewOutcome::Approved(rating));
+            EmitEvent::<Project>::emit_event(self.env(), TaskReviewed {
+                project_id: self.id,
+                task_id,
+                reviewer: self.env().caller(),
+                rating,
+            });
+            self.distribute_task_reward(task_id);
+            // organisation keeps project wise rating history of its members
+            for member in self.members_in_task.get(task_id).unwrap_or_default() {
+                self.report_rating_to_organisation(member, task_id, rating)?;
+            }
+            Ok(())
+        }
+
+        // manager sends the task in review back to its members for rework
+        #[ink(message)]
+        pub fn request_changes(&mut self, task_id: u16, reason_hash: Hash) -> Result<()> {
+            self.ensure_not_closed()?;
+
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized)
+            }
+            let mut task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            if task.status != TaskStatus::InReview {
+                return Err(Error::TaskNotInReview)
+            }
+            // members report progress of the rework from scratch
+            task.status = TaskStatus::Running(0);
+            self.task_by_id.insert(task_id, &task);
+            let round = self.review_rounds.get(task_id).unwrap_or(0) + 1;
+            self.review_rounds.insert(task_id, &round);
+            self.remove_from_review(task_id, ReviewOutcome::ChangesRequested(reason_hash));
+
+            EmitEvent::<Project>::emit_event(self.env(), ChangesRequested {
+                project_id: self.id,
+                task_id,
+                round,
+                reason_hash,
+            });
+            Ok(())
+        }
+
+        fn remove_from_review(&mut self, task_id: u16, outcome: ReviewOutcome) {
+            self.review_tasks.retain(|id| *id != task_id);
+            self.task_reviewers.remove(task_id);
+            self.peer_ratings.remove(task_id);
+            let mut history = self.review_history.get(task_id).unwrap_or_default();
+            history.push(ReviewRecord {
+                round: history.len() as u16 + 1,
+                reviewer: self.env().caller(),
+                outcome,
+                reviewed_at: self.env().block_timestamp(),
+            });
+            self.review_history.insert(task_id, &history);
+        }
+
+        // None turns off penalty for late delivery, applies to tasks assigned from now on
+        #[ink(message)]
+        pub fn set_late_penalty(&mut self, penalty: Option<LatePenalty>) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized)
+            }
+            self.late_penalty = penalty;
+            Ok(())
+        }
+
+        // task submitted(or still not submitted) after its end time
+        fn late_periods(&self, task: &Task) -> u32 {
+            let period = match self.task_late_penalty.get(task.task_id) {
+                Some(penalty) if penalty.period > 0 => penalty.period,
+                _ => return 0,
+            };
+            let delivered_at = self
+                .submitted_at
+                .get(task.task_id)
+                .unwrap_or(self.env().block_timestamp());
+            let late_by = delivered_at.saturating_sub(task.end_time);
+            late_by.div_ceil(period).min(u32::MAX as u64) as u32
+        }
+
+        // every matched skill multiplies and every open task divides the rating based score of the member
+        fn recommendation_for(&self, member: AccountId, required_skills: &[u16]) -> MemberRecommendation {
+            let open_tasks = self
+                .members_assigned_task_list
+                .get(member)
+                .unwrap_or_default()
+                .iter()
+                .filter(|id| {
+                    self.task_by_id
+                        .get(*id)
+                        .is_some_and(|task| task.status != TaskStatus::Completed)
+                })
+                .count() as u32;
+            let average_rating = self.get_member_vote_weight(member);
+            let skills = self.members_info.get(member).map(|info| info.skills).unwrap_or_default();
+            let matched_skills = required_skills
+                .iter()
+                .filter(|skill| skills.contains(skill))
+                .count() as u32;
+            MemberRecommendation {
+                member,
+                score: average_rating * 100 * (matched_skills as u64 + 1) / (open_tasks as u64 + 1),
+                matched_skills,
+                average_rating,
+                open_tasks,
+            }
+        }
+
+        fn is_overdue(&self, task: &Task) -> bool {
+            let pending = matches!(task.status, TaskStatus::NotYetStarted | TaskStatus::Running(_));
+            pending && self.env().block_timestamp() > task.end_time
+        }
+
+        // manager sets aside part of the project fund as reward for completing the task
+        #[ink(message)]
+        pub fn set_task_budget(&mut self, task_id: u16, amount: u128) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized)
+            }
+            let task = self.task_by_id.get(task_id);
+            if let Some(task) = task {
+                if task.status == TaskStatus::Completed {
+                    return Err(Error::TaskCompleted)
+                }
+            } else {
+                return Err(Error::TaskNotFound)
+            }
+
+            let old_budget = self.task_budget.get(task_id).unwrap_or(0);
+            // old budget of the task goes back to the fund before the new one is taken out
+            let available = self
+                .get_unbudgeted_fund()
+                .checked_add(old_budget)
+                .ok_or(Error::InsufficientProjectFund)?;
+            if amount > available {
+                return Err(Error::InsufficientProjectFund)
+            }
+            self.rewards_budgeted = self.rewards_budgeted - old_budget + amount;
+            self.escrow_balance = self.escrow_balance - old_budget + amount;
+            self.task_budget.insert(task_id, &amount);
+
+            Ok(())
+        }
+
+        // budget of completed task is released from escrow to the task members
+        fn distribute_task_reward(&mut self, task_id: u16) {
+            let budget = self.task_budget.get(task_id).unwrap_or(0);
+            let members = self.members_in_task.get(task_id).unwrap_or_default();
+            if budget > 0 && !members.is_empty() {
+                // reward withheld for late delivery goes back to project fund
+                let withheld = match (self.task_late_penalty.get(task_id), self.task_by_id.get(task_id)) {
+                    (Some(penalty), Some(task)) => {
+                        let cut = (penalty.reward_cut as u128 * self.late_periods(&task) as u128).min(100);
+                        budget * cut / 100
+                    }
+                    _ => 0,
+                };
+                self.rewards_budgeted -= withheld;
+                self.escrow_balance -= withheld;
+                let distributed = self.distribute_reward(&members, budget - withheld);
+                self.task_budget.insert(task_id, &0);
+
+                EmitEvent::<Project>::emit_event(self.env(), TaskRewardDistributed {
+                    project_id: self.id,
+                    task_id,
+                    amount: distributed,
+                });
+            }
+            self.release_reached_milestones(task_id);
+        }
+
+        // milestone budget is released once all of its tasks are completed
+        fn release_reached_milestones(&mut self, completed_task: u16) {
+            for milestone_id in self.milestone_ids.clone() {
+                let milestone = self.milestones.get(milestone_id).unwrap();
+                if milestone.task_ids.contains(&completed_task) {
+                    self.release_milestone_if_reached(milestone_id);
+                }
+            }
+        }
+
+        fn release_milestone_if_reached(&mut self, milestone_id: u16) {
+            let mut milestone = self.milestones.get(milestone_id).unwrap();
+            if milestone.budget == 0 {
+                return;
+            }
+            let reached = milestone.task_ids.iter().all(|id| {
+                self.task_by_id
+                    .get(id)
+                    .is_some_and(|task| task.status == TaskStatus::Completed)
+            });
+            if !reached {
+                return;
+            }
+
+            let mut members: Vec<AccountId> = Vec::new();
+            for id in milestone.task_ids.iter() {
+                for member in self.members_in_task.get(id).unwrap_or_default() {
+                    if !members.contains(&member) {
+                        members.push(member);
+                    }
+                }
+            }
+            if members.is_empty() {
+                return;
+            }
+            let distributed = self.distribute_reward(&members, milestone.budget);
+            milestone.budget = 0;
+            self.milestones.insert(milestone_id, &milestone);
+
+            EmitEvent::<Project>::emit_event(self.env(), MilestoneReleased {
+                project_id: self.id,
+                milestone_id,
+                amount: distributed,
+            });
+        }
+
+        // shares the budget among members weighted by their performance rating, returns the distributed amount
+        fn distribute_reward(&mut self, members: &[AccountId], budget: u128) -> u128 {
+            let weights: Vec<u64> = members
+                .iter()
+                .map(|member| self.get_member_vote_weight(*member))
+                .collect();
+            let total_weight: u64 = weights.iter().sum();
+
+            let mut distributed: u128 = 0;
+            for (member, weight) in members.iter().zip(weights.iter()) {
+                let share = budget * (*weight as u128) / (total_weight as u128);
+                let pending = self.pending_rewards.get(member).unwrap_or(0);
+                self.pending_rewards.insert(member, &(pending + share));
+                distributed += share;
+            }
+            self.total_pending_rewards += distributed;
+            // rounding leftover goes back to project fund
+            self.rewards_budgeted -= budget - distributed;
+            self.escrow_balance -= budget;
+            distributed
+        }
+
+        // project proposal contract(after members voted) or organisation moves the project forward
+        #[ink(message)]
+        pub fn update_project_status(&mut self, status: ProjectStatus) -> Result<()> {
+            let caller = self.env().caller();
+            let from_organisation = caller == self.organisation_contract;
+            if !from_organisation && Some(caller) != self.project_proposal_address {
+                return Err(Error::UnAuthorized);
+            }
+            self.ensure_status_reachable(status)?;
+            self.change_status(status)?;
+            // organisation records the status itself, calling it back would be a reentrant call
+            if !from_organisation {
+                self.report_status_to_organisation()?;
+            }
+            Ok(())
+        }
+
+        // project can't run or finish without an accepted approach
+        fn ensure_status_reachable(&self, status: ProjectStatus) -> Result<()> {
+            let needs_approach = status == ProjectStatus::Active || status == ProjectStatus::Completed;
+            if needs_approach && self.deadline.is_none() {
+                return Err(Error::ApproachNotDefined);
+            }
+            Ok(())
+        }
+
+        // lets project proposal reject status changes while creating the proposal
+        #[ink(message)]
+        pub fn is_status_reachable(&self, status: ProjectStatus) -> bool {
+            self.ensure_status_reachable(status).is_ok()
+        }
+
+        fn change_status(&mut self, status: ProjectStatus) -> Result<()> {
+            let is_valid = matches!(
+                (self.status, status),
+                (ProjectStatus::Recruiting, ProjectStatus::Planning)
+                    | (ProjectStatus::Recruiting, ProjectStatus::Active)
+                    | (ProjectStatus::Planning, ProjectStatus::Active)
+                    | (ProjectStatus::Active, ProjectStatus::Completed)
+                    | (ProjectStatus::Recruiting, ProjectStatus::Cancelled)
+                    | (ProjectStatus::Planning, ProjectStatus::Cancelled)
+                    | (ProjectStatus::Active, ProjectStatus::Cancelled)
+            );
+            if !is_valid {
+                return Err(Error::InvalidStatusTransition);
+            }
+            if self.status == ProjectStatus::Recruiting {
+                self.community_formation_status = CommunityFormationStatus::Closed;
+            }
+            self.status = status;
+            if status == ProjectStatus::Completed || status == ProjectStatus::Cancelled {
+                self.return_leftover_fund()?;
+            }
+            EmitEvent::<Project>::emit_event(self.env(), ProjectStatusChanged {
+                project_id: self.id,
+                status,
+            });
+            Ok(())
+        }
+
+        // escrow and all unspent fund goes back to organisation, only unclaimed rewards stay
+        fn return_leftover_fund(&mut self) -> Result<()> {
+            self.escrow_balance = 0;
+            for milestone_id in self.milestone_ids.clone() {
+                let mut milestone = self.milestones.get(milestone_id).unwrap();
+                milestone.budget = 0;
+                self.milestones.insert(milestone_id, &milestone);
+            }
+            for task_id in self.task_ids.iter() {
+                self.task_budget.remove(task_id);
+            }
+
+            let reserved = self.total_pending_rewards + self.env().minimum_balance();
+            let refund = self.env().balance().saturating_sub(reserved);
+            if refund > 0 {
+                if self.env().transfer(self.organisation_contract, refund).is_err() {
+                    return Err(Error::CannotTransferFund);
+                }
+                EmitEvent::<Project>::emit_event(self.env(), FundReturned {
+                    project_id: self.id,
+                    amount: refund,
+                });
+            }
+            Ok(())
+        }
+
+        // organisation keeps status of all of its projects
+        fn report_status_to_organisation(&self) -> Result<()> {
+            build_call::<DefaultEnvironment>()
+                .call(self.organisation_contract)
+                .gas_limit(0)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                        "record_project_status"
+                    )))
+                    .push_arg(self.id)
+                    .push_arg(self.status),
+                )
+                .returns::<Result<()>>()
+                .invoke()
+        }
+
+        fn report_rating_to_organisation(&self, member: AccountId, task_id: u16, rating: u8) -> Result<()> {
+            build_call::<DefaultEnvironment>()
+                .call(self.organisation_contract)
+                .gas_limit(0)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                        "record_member_rating"
+                    )))
+                    .push_arg(self.id)
+                    .push_arg(member)
+                    .push_arg(task_id)
+                    .push_arg(rating),
+                )
+                .returns::<Result<()>>()
+                .invoke()
+        }
+
+        fn ensure_not_closed(&self) -> Result<()> {
+            if self.status == ProjectStatus::Completed || self.status == ProjectStatus::Cancelled {
+                return Err(Error::ProjectClosed);
+            }
+            Ok(())
+        }
+
+        // pull based payout of rewards earned from completed tasks
+        #[ink(message)]
+        pub fn claim_rewards(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let pending = self.pending_rewards.get(caller).unwrap_or(0);
+            if pending == 0 {
+                return Err(Error::NoRewardsToClaim)
+            }
+            if self.env().balance() < pending {
+                return Err(Error::InsufficientProjectFund)
+            }
+            self.pending_rewards.remove(caller);
+            if self.env().transfer(caller, pending).is_err() {
+                return Err(Error::CannotTransferFund)
+            }
+            self.total_pending_rewards -= pending;
+
+            EmitEvent::<Project>::emit_event(self.env(), RewardsClaimed {
+                project_id: self.id,
+                member: caller,
+                amount: pending,
+            });
+            Ok(())
+        }
+
+
+        // check curr task and all children tasks(and their children) accordingly. These are the possible voters for deadline extension
+        #[ink(message)]
+        pub fn try_check_voters_for_deadline_extension(&self,task_id: u16, member: AccountId, check_task_only: bool) -> Result<bool> {
+            
+            let members = self.members_in_task.get(task_id);
+            let mut _is_possible = false;
+
+            if let Some(mut members) = members {
+                if !check_task_only {
+                    let children_task = self.task_by_id.get(task_id).unwrap().children_task;
+    
+                    for child in children_task {
+                        if let Some(mut child_members) = self.members_in_task.get(child) {
+                            members.append(&mut child_members);
+                        }
+                    }
+                }
+                if members.iter().find(|x| **x == member).is_some() {
+                    Ok(true)
+                }else{
+                    Ok(false)
+                }
+            }else{
+                return Err(Error::TaskNotFound)
+            }
+        }
+        
+
+        #[ink(message)]
+        pub fn is_possible_deadline(&self,task_id: u16, completion_time: Timestamp) -> Result<bool> {
+
+            let task = self.task_by_id.get(task_id);
+            let mut _is_possible = false;
+            if let Some(task) = task {
+                if task.deadline_policy == DeadlinePolicy::Rigid {
+                    return Err(Error::RigidDeadline)
+                }
+                _is_possible = self.check_neighbour_deadline(task,completion_time);
+            }else{
+                return Err(Error::TaskNotFound)
+            }
+
+            Ok(_is_possible)
+        }
+        // query all the children nodes if endtime of the task is not crossing the end time of neighbours
+        // otherwise task extension is not possible,
+        fn check_neighbour_deadline(&self,task: Task,endtime: Timestamp) -> bool {
+            let children_task = task.children_task;
+            let mut is_possible = true;
+            for child in children_task {
+                let child_task = self.task_by_id.get(child).unwrap();
+                if child_task.end_time <= endtime {
+                    is_possible = false;
+                }
+            }
+            is_possible
+        }
+        
+        #[ink(message)]
+        pub fn try_extend_task_deadline(&mut self,task_id: u16, completion_time: Timestamp) -> Result<()> {
+            self.ensure_not_closed()?;
+
+            let caller = self.env().caller();
+            // everything else already checked in proposal
+            if Some(caller) == self.project_proposal_address {
+                let task = self.task_by_id.get(task_id).unwrap();
+                if task.deadline_policy == DeadlinePolicy::Rigid {
+                    return Err(Error::RigidDeadline)
+                }
+                self.extend_deadline(task, completion_time);
+            } else {
+                return Err(Error::UnAuthorized);
+            }
+            Ok(())
+        }
+
+        // manager grants small extensions of flexible tasks without voting
+        #[ink(message)]
+        pub fn grant_deadline_extension(&mut self,task_id: u16, completion_time: Timestamp) -> Result<()> {
+            self.ensure_not_closed()?;
+
+            let caller = self.env().caller();
+            if caller != self.manager {
+                return Err(Error::UnAuthorized)
+            }
+            let task = self.task_by_id.get(task_id);
+            if let Some(task) = task {
+                let tolerance = match task.deadline_policy {
+                    DeadlinePolicy::Rigid => return Err(Error::RigidDeadline),
+                    DeadlinePolicy::Flexible(tolerance) => tolerance,
+                };
+                let planned_end_time = self.planned_end_time.get(task_id).unwrap_or(task.end_time);
+                if completion_time > planned_end_time.saturating_add(tolerance) {
+                    return Err(Error::BeyondExtensionTolerance)
+                }
+                if completion_time <= task.end_time || !self.check_neighbour_deadline(task.clone(), completion_time) {
+                    return Err(Error::DeadlineNotPossible)
+                }
+                self.extend_deadline(task, completion_time);
+            } else {
+                return Err(Error::TaskNotFound)
+            }
+            Ok(())
+        }
+
+        fn extend_deadline(&mut self, mut task: Task, completion_time: Timestamp) {
+            let task_id = task.task_id;
+            task.end_time = completion_time;
+            self.task_by_id.insert(task_id, &task);
+            self.build_deadline_graph(task_id);
+            EmitEvent::<Project>::emit_event(self.env(), DeadlineExtended {
+                project_id: self.id,
+                task_id,
+                end_time: completion_time,
+            });
+        }
+
+        /*
+            project proposal sends the whole task graph after adding, updating or removing a task
+            1. graph is already validated by project proposal
+            2. tasks missing from the graph are removed and their budget goes back to project fund
+            3. budget of new tasks is kept in escrow, budget of existing tasks is managed by set_task_budget
+        */
+        #[ink(message)]
+        pub fn try_update_task_graph(&mut self, tasks: Vec<Task>, root_tasks: Vec<u16>) -> Result<()> {
+            self.ensure_not_closed()?;
+            let caller = self.env().caller();
+            if Some(caller) != self.project_proposal_address {
+                return Err(Error::UnAuthorized);
+            }
+            if self.deadline.is_none() {
+                return Err(Error::ApproachNotDefined);
+            }
+
+            let task_ids: Vec<u16> = tasks.iter().map(|task| task.task_id).collect();
+            for task_id in self.task_ids.clone() {
+                if !task_ids.contains(&task_id) {
+                    self.remove_task(task_id);
+                }
+            }
+            let mut new_task_ids: Vec<u16> = Vec::new();
+            for mut task in tasks {
+                if let Some(old_task) = self.task_by_id.get(task.task_id) {
+                    // deadline of an existing task is only changed by grant_deadline_extension
+                    task.end_time = old_task.end_time;
+                    task.deadline_policy = old_task.deadline_policy;
+                } else {
+                    if task.budget > self.get_unbudgeted_fund() {
+                        return Err(Error::InsufficientProjectFund);
+                    }
+                    if task.budget > 0 {
+                        self.task_budget.insert(task.task_id, &task.budget);
+                        self.rewards_budgeted += task.budget;
+                        self.escrow_balance += task.budget;
+                    }
+                    self.planned_end_time.insert(task.task_id, &task.end_time);
+                    new_task_ids.push(task.task_id);
+                }
+                self.task_by_id.insert(task.task_id, &task);
+            }
+            for task_id in new_task_ids {
+                self.record_planned_duration(task_id);
+            }
+            self.task_ids = task_ids;
+            self.root_tasks = root_tasks;
+            for root_task in self.root_tasks.clone() {
+                self.build_deadline_graph(root_task);
+            }
+            EmitEvent::<Project>::emit_event(self.env(), TaskGraphUpdated {
+                project_id: self.id,
+                task_count: self.task_ids.len() as u32,
+            });
+            Ok(())
+        }
+
+        fn remove_task(&mut self, task_id: u16) {
+            let budget = self.task_budget.get(task_id).unwrap_or(0);
+            self.rewards_budgeted -= budget;
+            self.escrow_balance -= budget;
+            self.task_budget.remove(task_id);
+
+            for member in self.members_in_task.get(task_id).unwrap_or_default() {
+                let mut assigned_tasks = self.members_assigned_task_list.get(member).unwrap_or_default();
+                assigned_tasks.retain(|id| *id != task_id);
+                self.members_assigned_task_list.insert(member, &assigned_tasks);
+            }
+            self.members_in_task.remove(task_id);
+            self.task_by_id.remove(task_id);
+            self.planned_end_time.remove(task_id);
+            self.planned_duration.remove(task_id);
+            self.review_tasks.retain(|id| *id != task_id);
+            self.task_reviewers.remove(task_id);
+            self.peer_ratings.remove(task_id);
+            self.review_seed.remove(task_id);
+            self.task_late_penalty.remove(task_id);
+
+            // milestone left without any task can never be reached
+            for milestone_id in self.milestone_ids.clone() {
+                let mut milestone = self.milestones.get(milestone_id).unwrap();
+                if !milestone.task_ids.contains(&task_id) {
+                    continue;
+                }
+                milestone.task_ids.retain(|id| *id != task_id);
+                if milestone.task_ids.is_empty() {
+                    self.rewards_budgeted -= milestone.budget;
+                    self.escrow_balance -= milestone.budget;
+                    milestone.budget = 0;
+                }
+                self.milestones.insert(milestone_id, &milestone);
+                // remaining tasks may all be completed already
+                self.release_milestone_if_reached(milestone_id);
+            }
+        }
+
+        // planned start is the own start time of the task or the end of its last parent
+        fn record_planned_duration(&mut self, task_id: u16) {
+            let project_start_date = self.deadline.clone().unwrap().project_start_date;
+            let task = self.task_by_id.get(task_id).unwrap();
+            let planned_start = task.start_time.unwrap_or_else(|| {
+                task.start_after
+                    .iter()
+                    .filter_map(|parent| self.task_by_id.get(parent))
+                    .map(|parent| parent.end_time)
+                    .max()
+                    .unwrap_or(project_start_date)
+            });
+            self.planned_duration.insert(task_id, &task.end_time.saturating_sub(planned_start));
+        }
+
+        /*
             1. it will take the task node and update all the subtasks
         */
         fn build_deadline_graph(&mut self, root_task: u16){
@@ -509,6 +1945,110 @@ pub mod project {
             }
         }
 
+        /*
+            critical path method over the task graph
+            1. planned duration of a task is kept fixed, an extension only adds to duration of the extended task
+            2. task can't start before its own planned start or before all of its parents finish
+            3. latest finish of the last tasks is the earliest possible project finish
+        */
+        fn compute_schedule(&self, extension: Option<(u16, Timestamp)>) -> Result<Vec<TaskSchedule>> {
+            let deadline = self.deadline.clone();
+            let project_start_date = if let Some(deadline) = deadline {
+                deadline.project_start_date
+            } else {
+                return Err(Error::DeadLineNotDefined);
+            };
+            let mut task_by_id: BTreeMap<u16, Task> = BTreeMap::new();
+            for task_id in self.task_ids.iter() {
+                task_by_id.insert(*task_id, self.task_by_id.get(task_id).unwrap());
+            }
+            if let Some((task_id, _)) = extension {
+                if !task_by_id.contains_key(&task_id) {
+                    return Err(Error::TaskNotFound);
+                }
+            }
+
+            // parents are scheduled before their children
+            let mut pending_parents: BTreeMap<u16, usize> = task_by_id
+                .iter()
+                .map(|(id, task)| (*id, task.start_after.len()))
+                .collect();
+            let mut ordered: Vec<u16> = self
+                .task_ids
+                .iter()
+                .filter(|id| pending_parents[*id] == 0)
+                .copied()
+                .collect();
+            let mut next = 0;
+            while next < ordered.len() {
+                let u = ordered[next];
+                next += 1;
+                for child in task_by_id[&u].children_task.iter() {
+                    let remaining = pending_parents.get_mut(child).unwrap();
+                    *remaining -= 1;
+                    if *remaining == 0 {
+                        ordered.push(*child);
+                    }
+                }
+            }
+
+            let mut duration: BTreeMap<u16, Timestamp> = BTreeMap::new();
+            let mut earliest: BTreeMap<u16, (Timestamp, Timestamp)> = BTreeMap::new();
+            for id in ordered.iter() {
+                let task = &task_by_id[id];
+                let end_time = match extension {
+                    Some((task_id, completion_time)) if task_id == *id => completion_time,
+                    _ => task.end_time,
+                };
+                // pushing the start of a child doesn't move its end time, so duration is not end - start
+                let planned_end_time = self.planned_end_time.get(id).unwrap_or(task.end_time);
+                let task_duration = self
+                    .planned_duration
+                    .get(id)
+                    .unwrap_or_default()
+                    .saturating_add(end_time.saturating_sub(planned_end_time));
+                let earliest_start = task
+                    .start_after
+                    .iter()
+                    .map(|parent| earliest[parent].1)
+                    .fold(task.start_time.unwrap_or(project_start_date), Timestamp::max);
+                duration.insert(*id, task_duration);
+                earliest.insert(*id, (earliest_start, earliest_start + task_duration));
+            }
+
+            let project_finish = earliest
+                .values()
+                .map(|(_, earliest_finish)| *earliest_finish)
+                .max()
+                .unwrap_or(project_start_date);
+            let mut latest_start: BTreeMap<u16, Timestamp> = BTreeMap::new();
+            let mut schedule: BTreeMap<u16, TaskSchedule> = BTreeMap::new();
+            for id in ordered.iter().rev() {
+                let latest_finish = task_by_id[id]
+                    .children_task
+                    .iter()
+                    .map(|child| latest_start[child])
+                    .fold(project_finish, Timestamp::min);
+                let start = latest_finish.saturating_sub(duration[id]);
+                let (earliest_start, earliest_finish) = earliest[id];
+                latest_start.insert(*id, start);
+                schedule.insert(*id, TaskSchedule {
+                    task_id: *id,
+                    earliest_start,
+                    earliest_finish,
+                    latest_start: start,
+                    latest_finish,
+                    slack: start.saturating_sub(earliest_start),
+                });
+            }
+
+            Ok(self
+                .task_ids
+                .iter()
+                .map(|id| schedule.remove(id).unwrap())
+                .collect())
+        }
+
         // Queries      Queries     Queries    Queries   Queries      Queries      Queries       Queries
         // Queries      Queries     Queries    Queries   Queries      Queries      Queries       Queries
 
@@ -527,6 +2067,31 @@ pub mod project {
             self.member_list.clone()
         }
 
+        #[ink(message)]
+        pub fn get_manager(&self) -> AccountId {
+            self.manager
+        }
+
+        #[ink(message)]
+        pub fn get_organisation(&self) -> AccountId {
+            self.organisation_contract
+        }
+
+        #[ink(message)]
+        pub fn show_shortlisted_members(&self) -> Vec<AccountId> {
+            self.shortlisted_members.clone()
+        }
+
+        #[ink(message)]
+        pub fn get_rejection_reason(&self, applicant: AccountId) -> Option<Hash> {
+            self.rejected_applicants.get(applicant)
+        }
+
+        #[ink(message)]
+        pub fn is_recruitment_open(&self) -> bool {
+            self.community_formation_status == CommunityFormationStatus::Running
+        }
+
         #[ink(message)]
         pub fn check_member(&self, address: AccountId) -> bool {
             let member_info = self.members_info.get(address);
@@ -542,6 +2107,21 @@ pub mod project {
             self.curr_member_count.clone()
         }
         #[ink(message)]
+        pub fn get_task(&self, task_id: u16) -> Option<Task> {
+            self.task_by_id.get(task_id)
+        }
+        #[ink(message)]
+        pub fn get_tasks(&self) -> Vec<Task> {
+            self.task_ids
+                .iter()
+                .map(|id| self.task_by_id.get(id).unwrap())
+                .collect()
+        }
+        #[ink(message)]
+        pub fn get_deadline(&self) -> Option<DeadLine> {
+            self.deadline.clone()
+        }
+        #[ink(message)]
         pub fn get_task_members(&self,task_id: u16) -> Vec<AccountId> {
             let assigned_members_in_the_task = self.members_in_task.get(task_id);
             if let Some(assigned_members) = assigned_members_in_the_task {
@@ -554,6 +2134,354 @@ pub mod project {
         pub fn get_review_task(&self) -> Vec<u16> {
             self.review_tasks.clone()
         }
+        #[ink(message)]
+        pub fn get_pending_rewards(&self, member: AccountId) -> u128 {
+            self.pending_rewards.get(member).unwrap_or(0)
+        }
+        #[ink(message)]
+        pub fn get_task_budget(&self, task_id: u16) -> u128 {
+            self.task_budget.get(task_id).unwrap_or(0)
+        }
+        #[ink(message)]
+        pub fn get_milestone(&self, milestone_id: u16) -> Option<Milestone> {
+            self.milestones.get(milestone_id)
+        }
+        #[ink(message)]
+        pub fn get_escrow_balance(&self) -> u128 {
+            self.escrow_balance
+        }
+        #[ink(message)]
+        pub fn get_project_status(&self) -> ProjectStatus {
+            self.status
+        }
+        /// applicants having all of the given skills
+        #[ink(message)]
+        pub fn filter_interested_members(&self, required_skills: Vec<u16>) -> Vec<AccountId> {
+            self.interested_members
+                .iter()
+                .filter(|address| {
+                    let skills = self
+                        .interested_members_info
+                        .get(*address)
+                        .map(|info| info.skills)
+                        .unwrap_or_default();
+                    required_skills.iter().all(|skill| skills.contains(skill))
+                })
+                .copied()
+                .collect()
+        }
+        /// applicants having all the skills required by the task
+        #[ink(message)]
+        pub fn get_applicants_for_task(&self, task_id: u16) -> Result<Vec<AccountId>> {
+            let task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            Ok(self.filter_interested_members(task.required_skills))
+        }
+        /// members not yet working on the task, better rated, skilled and less loaded members come first
+        #[ink(message)]
+        pub fn recommend_members_for_task(&self, task_id: u16) -> Result<Vec<MemberRecommendation>> {
+            let task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            let members_in_task = self.members_in_task.get(task_id).unwrap_or_default();
+            let mut recommendations: Vec<MemberRecommendation> = self
+                .member_list
+                .iter()
+                .filter(|member| !members_in_task.contains(member))
+                .map(|member| self.recommendation_for(*member, &task.required_skills))
+                .collect();
+            recommendations.sort_by(|a, b| {
+                b.score
+                    .cmp(&a.score)
+                    .then(a.open_tasks.cmp(&b.open_tasks))
+            });
+            Ok(recommendations)
+        }
+        #[ink(message)]
+        pub fn get_review_mode(&self) -> ReviewMode {
+            self.review_mode
+        }
+        #[ink(message)]
+        pub fn get_task_reviewers(&self, task_id: u16) -> Vec<AccountId> {
+            self.task_reviewers.get(task_id).unwrap_or_default()
+        }
+        #[ink(message)]
+        pub fn get_peer_ratings(&self, task_id: u16) -> Vec<PeerRating> {
+            self.peer_ratings.get(task_id).unwrap_or_default()
+        }
+        #[ink(message)]
+        pub fn get_review_rounds(&self, task_id: u16) -> u16 {
+            self.review_rounds.get(task_id).unwrap_or(0)
+        }
+        #[ink(message)]
+        pub fn get_review_history(&self, task_id: u16) -> Vec<ReviewRecord> {
+            self.review_history.get(task_id).unwrap_or_default()
+        }
+        /// tasks not submitted for review even after their end time
+        #[ink(message)]
+        pub fn get_overdue_tasks(&self) -> Vec<u16> {
+            self.task_ids
+                .iter()
+                .filter(|id| self.is_overdue(&self.task_by_id.get(*id).unwrap()))
+                .copied()
+                .collect()
+        }
+        #[ink(message)]
+        pub fn is_task_overdue(&self, task_id: u16) -> Result<bool> {
+            let task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            Ok(self.is_overdue(&task))
+        }
+        /// late periods counted so far(or at submission) under the current penalty
+        #[ink(message)]
+        pub fn get_late_periods(&self, task_id: u16) -> Result<u32> {
+            let task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            Ok(self.late_periods(&task))
+        }
+        #[ink(message)]
+        pub fn get_late_penalty(&self) -> Option<LatePenalty> {
+            self.late_penalty.clone()
+        }
+        #[ink(message)]
+        pub fn get_task_late_penalty(&self, task_id: u16) -> Option<LatePenalty> {
+            self.task_late_penalty.get(task_id)
+        }
+        /// latest end time the manager can grant without a proposal, None for rigid tasks
+        #[ink(message)]
+        pub fn get_extension_limit(&self, task_id: u16) -> Result<Option<Timestamp>> {
+            let task = self.task_by_id.get(task_id).ok_or(Error::TaskNotFound)?;
+            let planned_end_time = self.planned_end_time.get(task_id).unwrap_or(task.end_time);
+            match task.deadline_policy {
+                DeadlinePolicy::Rigid => Ok(None),
+                DeadlinePolicy::Flexible(tolerance) => Ok(Some(planned_end_time.saturating_add(tolerance))),
+            }
+        }
+        #[ink(message)]
+        pub fn get_task_schedule(&self) -> Result<Vec<TaskSchedule>> {
+            self.compute_schedule(None)
+        }
+        /// tasks without any slack ordered by their earliest start
+        #[ink(message)]
+        pub fn get_critical_path(&self) -> Result<Vec<u16>> {
+            let mut critical_tasks: Vec<TaskSchedule> = self
+                .compute_schedule(None)?
+                .into_iter()
+                .filter(|schedule| schedule.slack == 0)
+                .collect();
+            critical_tasks.sort_by_key(|schedule| (schedule.earliest_start, schedule.earliest_finish));
+            Ok(critical_tasks.iter().map(|schedule| schedule.task_id).collect())
+        }
+        #[ink(message)]
+        pub fn get_earliest_project_finish(&self) -> Result<Timestamp> {
+            self.compute_schedule(None)?
+                .iter()
+                .map(|schedule| schedule.earliest_finish)
+                .max()
+                .ok_or(Error::TaskNotFound)
+        }
+        /// projected project finish if extending the task would cross the project completion date
+        #[ink(message)]
+        pub fn check_extension_overrun(
+            &self,
+            task_id: u16,
+            completion_time: Timestamp,
+        ) -> Result<Option<Timestamp>> {
+            let project_finish = self
+                .compute_schedule(Some((task_id, completion_time)))?
+                .iter()
+                .map(|schedule| schedule.earliest_finish)
+                .max()
+                .unwrap_or_default();
+            let project_completion_date = self.deadline.clone().unwrap().project_completion_date;
+            if project_finish > project_completion_date {
+                Ok(Some(project_finish))
+            } else {
+                Ok(None)
+            }
+        }
+        /// fund which is not yet committed to any task or milestone budget
+        #[ink(message)]
+        pub fn get_unbudgeted_fund(&self) -> u128 {
+            self.total_fund_allocated.saturating_sub(self.rewards_budgeted)
+        }
+        /// average performance rating over the reviewed tasks of the member(atleast 1)
+        #[ink(message)]
+        pub fn get_member_vote_weight(&self, member: AccountId) -> u64 {
+            let mut rating_sum: u64 = 0;
+            let mut rated_tasks: u64 = 0;
+            if let Some(assigned_tasks) = self.members_assigned_task_list.get(member) {
+                for task_id in assigned_tasks {
+                    if let Some(task) = self.task_by_id.get(task_id) {
+                        if let Some(rating) = task.performance_rating {
+                            rating_sum += rating as u64;
+                            rated_tasks += 1;
+                        }
+                    }
+                }
+            }
+            rating_sum.checked_div(rated_tasks).unwrap_or(1).max(1)
+        }
+        #[ink(message)]
+        pub fn total_vote_weight(&self) -> u64 {
+            self.member_list
+                .iter()
+                .map(|member| self.get_member_vote_weight(*member))
+                .sum()
+        }
+
+    }
+
+    /// budgets of all tasks and milestones of the approach, milestone ids should be unique
+    pub fn approach_escrow(approach: &AcceptedApproach) -> Result<u128> {
+        let mut milestone_ids: Vec<u16> = approach
+            .milestones
+            .iter()
+            .map(|milestone| milestone.milestone_id)
+            .collect();
+        milestone_ids.sort_unstable();
+        milestone_ids.dedup();
+        if milestone_ids.len() != approach.milestones.len() {
+            return Err(Error::DuplicateMilestoneId);
+        }
+        // a sum beyond u128 can never be funded
+        approach
+            .tasks
+            .iter()
+            .map(|task| task.budget)
+            .chain(approach.milestones.iter().map(|milestone| milestone.budget))
+            .try_fold(0u128, |escrow, budget| escrow.checked_add(budget))
+            .ok_or(Error::InsufficientProjectFund)
+    }
+
+    /// sorts and dedups skill tag ids, atmost MAX_SKILL_TAGS are allowed
+    pub fn normalize_skills(mut skills: Vec<u16>) -> Result<Vec<u16>> {
+        skills.sort_unstable();
+        skills.dedup();
+        if skills.len() > MAX_SKILL_TAGS {
+            return Err(Error::TooManySkillTags);
+        }
+        Ok(skills)
+    }
+
+    fn aggregate_ratings(ratings: &mut [u8], aggregation: RatingAggregation) -> u8 {
+        ratings.sort_unstable();
+        let len = ratings.len();
+        match aggregation {
+            RatingAggregation::Median => {
+                if len % 2 == 1 {
+                    ratings[len / 2]
+                } else {
+                    ((ratings[len / 2 - 1] as u16 + ratings[len / 2] as u16) / 2) as u8
+                }
+            }
+            RatingAggregation::TrimmedMean => {
+                let kept = if len >= 3 { &ratings[1..len - 1] } else { &ratings[..] };
+                let sum: u32 = kept.iter().map(|rating| *rating as u32).sum();
+                (sum / kept.len() as u32) as u8
+            }
+        }
+    }
 
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use ink::env::DefaultEnvironment;
+
+        fn accounts() -> test::DefaultAccounts<DefaultEnvironment> {
+            test::default_accounts::<DefaultEnvironment>()
+        }
+
+        fn task(task_id: u16, start_after: &[u16], end_time: Timestamp, children_task: &[u16]) -> Task {
+            Task {
+                task_id,
+                start_after: start_after.to_vec(),
+                start_time: None,
+                end_time,
+                status: TaskStatus::NotYetStarted,
+                performance_rating: None,
+                details: Vec::new(),
+                children_task: children_task.to_vec(),
+                budget: 0,
+                deadline_policy: DeadlinePolicy::Flexible(100),
+                required_skills: Vec::new(),
+            }
+        }
+
+        // project with the tasks stored the way an accepted approach stores them, bob is the manager
+        fn project_with_tasks(tasks: Vec<Task>) -> Project {
+            test::set_caller::<DefaultEnvironment>(accounts().alice);
+            let business_idea = BusinessIdea {
+                title: String::from("idea"),
+                info_count: 0,
+                info_list: Vec::new(),
+            };
+            let mut project = Project::new(0, business_idea, accounts().bob, 0, 2, accounts().django);
+            project.deadline = Some(DeadLine {
+                project_start_date: 0,
+                project_completion_date: 100,
+            });
+            for task in tasks.iter() {
+                project.task_by_id.insert(task.task_id, task);
+                project.planned_end_time.insert(task.task_id, &task.end_time);
+                project.task_ids.push(task.task_id);
+            }
+            for task in tasks.iter() {
+                project.record_planned_duration(task.task_id);
+            }
+            project
+        }
+
+        #[ink::test]
+        fn schedule_after_extension_keeps_child_duration() {
+            let mut project = project_with_tasks(vec![task(0, &[], 10, &[1]), task(1, &[0], 20, &[])]);
+            assert_eq!(project.get_earliest_project_finish().unwrap(), 20);
+            let projected = project
+                .compute_schedule(Some((0, 15)))
+                .unwrap()
+                .iter()
+                .map(|schedule| schedule.earliest_finish)
+                .max();
+            assert_eq!(projected, Some(25));
+
+            test::set_caller::<DefaultEnvironment>(accounts().bob);
+            assert!(project.grant_deadline_extension(0, 15).is_ok());
+            assert_eq!(project.get_earliest_project_finish().unwrap(), 25);
+            let schedule = project.get_task_schedule().unwrap();
+            assert_eq!(schedule[1].earliest_start, 15);
+            assert_eq!(schedule[1].earliest_finish, 25);
+            assert_eq!(project.get_critical_path().unwrap(), vec![0, 1]);
+        }
+
+        #[test]
+        fn median_of_odd_count_is_middle_rating() {
+            assert_eq!(aggregate_ratings(&mut [90, 10, 50], RatingAggregation::Median), 50);
+        }
+
+        #[test]
+        fn median_of_even_count_averages_middle_ratings() {
+            assert_eq!(aggregate_ratings(&mut [80, 10, 40, 90], RatingAggregation::Median), 60);
+            // average of 40 and 41 rounds down
+            assert_eq!(aggregate_ratings(&mut [41, 40], RatingAggregation::Median), 40);
+        }
+
+        #[test]
+        fn median_of_high_ratings_does_not_overflow() {
+            assert_eq!(aggregate_ratings(&mut [255, 253], RatingAggregation::Median), 254);
+        }
+
+        #[test]
+        fn trimmed_mean_drops_highest_and_lowest() {
+            assert_eq!(aggregate_ratings(&mut [0, 60, 70, 100], RatingAggregation::TrimmedMean), 65);
+        }
+
+        #[test]
+        fn trimmed_mean_keeps_all_of_fewer_than_three() {
+            assert_eq!(aggregate_ratings(&mut [70], RatingAggregation::TrimmedMean), 70);
+            assert_eq!(aggregate_ratings(&mut [0, 100], RatingAggregation::TrimmedMean), 50);
+        }
+
+        #[test]
+        fn trimmed_mean_rounds_down() {
+            // 10 + 20 + 20 kept out of five ratings, 50 / 3
+            assert_eq!(aggregate_ratings(&mut [0, 10, 20, 20, 255], RatingAggregation::TrimmedMean), 16);
+            assert_eq!(aggregate_ratings(&mut [255, 255, 255], RatingAggregation::TrimmedMean), 255);
+        }
     }
 }
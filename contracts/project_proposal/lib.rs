@@ -5,17 +5,37 @@ pub use self::pproposal::{Pproposal, PproposalRef};
 #[ink::contract]
 pub mod pproposal {
 
+    use ink::codegen::EmitEvent;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::DefaultEnvironment;
+    use ink::prelude::collections::{BTreeMap, BTreeSet};
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
     use ink::storage::traits::StorageLayout;
     use ink::storage::Mapping;
-    use project::{AcceptedApproach, Error, ProjectRef, Result, Task};
+    use project::{
+        approach_escrow, normalize_skills, AcceptedApproach, Error, ProjectRef, ProjectStatus, Result, Task,
+        TaskStatus, VotingMode,
+    };
+
+    /// upper bound on approaches competing in one proposal
+    pub const MAX_COMPETING_APPROACHES: u8 = 5;
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub enum ProposalType {
         ProposeApproach,
         ExtendDeadLine,
+        ChangeVotingMode,
+        ChangeProjectStatus,
+        RemoveMember,
+        AddTask,
+        UpdateTask,
+        RemoveTask,
+        ChangeManager,
+        /// several approaches compete, members pick the winner through ranked ballots
+        ApproachCompetition,
+        ChangeEarlyResolution,
     }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone)]
@@ -61,6 +81,8 @@ pub mod pproposal {
         voter: AccountId,
         /// vote status.
         vote: Vote,
+        /// weight with which the vote was counted
+        weight: u64,
     }
     #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
@@ -70,6 +92,35 @@ pub mod pproposal {
         votes: Votes,
     }
 
+    #[derive(Debug, Copy, PartialEq, Eq, scale::Encode, scale::Decode, Clone)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum TallyMethod {
+        /// approach with least first preferences is eliminated till one holds the majority
+        InstantRunoff,
+        /// approach ranked at position p out of n approaches earns n - 1 - p points
+        Borda,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// approaches are submitted till submission_end, ranked ballots are cast from then till proposal end
+    pub struct Competition {
+        tally: TallyMethod,
+        submission_end: Timestamp,
+        /// submitter of every approach, index is the entry id
+        submitters: Vec<AccountId>,
+        winner: Option<u8>,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct RankedBallot {
+        voter: AccountId,
+        /// entry ids from most to least preferred, entries left out are not preferred at all
+        ranking: Vec<u8>,
+        weight: u64,
+    }
+
     #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub struct DeadLineExtensionInfo {
@@ -78,6 +129,32 @@ pub mod pproposal {
         details: Vec<String>,
     }
 
+    #[derive(Debug, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum TaskChange {
+        Add(Task),
+        /// status, rating and budget of the task are kept as they are in project
+        Update(Task),
+        /// dependent tasks start after the parents of the removed task instead
+        Remove(u16),
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// open tasks of the member are handed over to the replacement(if any)
+    pub struct MemberRemovalInfo {
+        member: AccountId,
+        replacement: Option<AccountId>,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// proposals of this type are closed before their end time once uncast votes can't change the result
+    pub struct EarlyResolutionInfo {
+        proposal_type: ProposalType,
+        enabled: bool,
+    }
+
     #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub struct ProposalInfo {
@@ -90,6 +167,64 @@ pub mod pproposal {
         status: Status,
     }
 
+    #[ink(event)]
+    pub struct ProposalCreated {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        project_address: AccountId,
+        #[ink(topic)]
+        proposer: AccountId,
+        proposal_type: ProposalType,
+    }
+
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        voter: AccountId,
+        vote: Vote,
+        weight: u64,
+    }
+
+    #[ink(event)]
+    pub struct ApproachSubmitted {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        submitter: AccountId,
+        entry: u8,
+    }
+
+    #[ink(event)]
+    pub struct RankedBallotCast {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        voter: AccountId,
+        weight: u64,
+    }
+
+    /// extension would push the project past its completion date
+    #[ink(event)]
+    pub struct DeadlineOverrunFlagged {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        project_address: AccountId,
+        projected_finish: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct ProposalStatusChanged {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        project_address: AccountId,
+        status: Status,
+    }
+
     #[ink(storage)]
     pub struct Pproposal {
         next_proposal_id: u64,
@@ -103,9 +238,24 @@ pub mod pproposal {
         proposal_duration: Timestamp,
         // net voting should be checked. If voting is less than this quorum number then reject proposal after expiratiion period(it is a percentage value)
         quorum: u64,
+        voting_mode: VotingMode,
         // if proposal type is ProposeApproach then store the approach here after sorting out graph complecations
         approach_info: Mapping<u64, AcceptedApproach>,
         deadline_extension_info: Mapping<u64, DeadLineExtensionInfo>,
+        voting_mode_info: Mapping<u64, VotingMode>,
+        project_status_info: Mapping<u64, ProjectStatus>,
+        member_removal_info: Mapping<u64, MemberRemovalInfo>,
+        task_change_info: Mapping<u64, TaskChange>,
+        manager_info: Mapping<u64, AccountId>,
+        competition_info: Mapping<u64, Competition>,
+        /// (proposal id, entry id) -> approach submitted to the competition
+        competing_approaches: Mapping<(u64, u8), AcceptedApproach>,
+        ranked_ballots: Mapping<u64, Vec<RankedBallot>>,
+        early_resolution_info: Mapping<u64, EarlyResolutionInfo>,
+        /// proposal types allowed to be resolved before their end time
+        early_resolution: Mapping<ProposalType, bool>,
+        // projected project finish of extension proposals crossing project completion date
+        deadline_overrun: Mapping<u64, Timestamp>,
 
         project_address: AccountId,
     }
@@ -113,7 +263,7 @@ pub mod pproposal {
     impl Pproposal {
         // checkout accountid(you will be providing string address)
         #[ink(constructor)]
-        pub fn new(project_address: AccountId) -> Self {
+        pub fn new(project_address: AccountId, voting_mode: VotingMode) -> Self {
             // only owner of organisation should instantiate this contract
             let default_duration: u64 = 172800000; //2 days in millisecond
             let default_quorum: u64 = 10 * 1_000_000; //10 percent for upto 6 decimal places
@@ -124,9 +274,21 @@ pub mod pproposal {
                 voting_result: Mapping::new(),
                 proposal_duration: default_duration,
                 quorum: default_quorum,
+                voting_mode,
                 project_address,
                 approach_info: Mapping::new(),
                 deadline_extension_info: Mapping::new(),
+                voting_mode_info: Mapping::new(),
+                project_status_info: Mapping::new(),
+                member_removal_info: Mapping::new(),
+                task_change_info: Mapping::new(),
+                manager_info: Mapping::new(),
+                competition_info: Mapping::new(),
+                competing_approaches: Mapping::new(),
+                ranked_ballots: Mapping::new(),
+                early_resolution_info: Mapping::new(),
+                early_resolution: Mapping::new(),
+                deadline_overrun: Mapping::new(),
             }
         }
 
@@ -144,6 +306,13 @@ pub mod pproposal {
             proposal_type: ProposalType,
             approach: Option<AcceptedApproach>, // the list of task should be in specified order(only depend on previous task)
             deadline_extension_info: Option<DeadLineExtensionInfo>,
+            change_voting_mode: Option<VotingMode>,
+            change_project_status: Option<ProjectStatus>,
+            remove_member: Option<MemberRemovalInfo>,
+            change_task: Option<TaskChange>,
+            change_manager: Option<AccountId>,
+            tally_method: Option<TallyMethod>,
+            change_early_resolution: Option<EarlyResolutionInfo>,
         ) -> Result<u64> {
             let id = self.next_proposal_id;
             let caller = self.env().caller();
@@ -154,23 +323,8 @@ pub mod pproposal {
 
             match proposal_type {
                 ProposalType::ProposeApproach => {
-                    if let Some(mut approach) = approach {
-                        if approach.project_start_date < proposal_span.end_time {
-                            return Err(Error::CannotStartProjectBeforeVoting);
-                        }
-
-                        // create node
-                        let mut task_by_id: Mapping<u16, Task> = Mapping::new();
-                        let mut task_list: Vec<Task> = Vec::new(); // contains all task to be sent to project after passing
-
-                        for task in approach.tasks {
-                            if task.end_time > approach.project_completion_date {
-                                return Err(Error::TaskDeadlineCannotCrossPojectDeadLine);
-                            }
-                            create_task_nodes(&mut task_by_id, &mut task_list, task);
-                        }
-                        // update task list with graph node informations
-                        approach.tasks = task_list;
+                    if let Some(approach) = approach {
+                        let approach = self.validate_approach(approach, proposal_span.end_time)?;
 
                         let proposal_info = ProposalInfo {
                             proposal_type: ProposalType::ProposeApproach,
@@ -215,7 +369,8 @@ pub mod pproposal {
                                     deadline_info.task_id,
                                     deadline_info.deadline,
                                 ) {
-                                    Ok(_) => {
+                                    Ok(false) => return Err(Error::DeadlineNotPossible),
+                                    Ok(true) => {
                                         let proposal_info = ProposalInfo {
                                             proposal_type: ProposalType::ExtendDeadLine,
                                             proposal_id: id,
@@ -227,6 +382,23 @@ pub mod pproposal {
                                         self.proposals.insert(id, &proposal_info);
                                         // self.open_proposals_id.push(id);
                                         self.deadline_extension_info.insert(id, &deadline_info);
+                                        // voters should know before voting that project will be late
+                                        if let Some(projected_finish) = project_instance
+                                            .check_extension_overrun(
+                                                deadline_info.task_id,
+                                                deadline_info.deadline,
+                                            )?
+                                        {
+                                            self.deadline_overrun.insert(id, &projected_finish);
+                                            EmitEvent::<Pproposal>::emit_event(
+                                                self.env(),
+                                                DeadlineOverrunFlagged {
+                                                    proposal_id: id,
+                                                    project_address: self.project_address,
+                                                    projected_finish,
+                                                },
+                                            );
+                                        }
 
                                         let votes = Votes {
                                             yes: 0,
@@ -249,8 +421,275 @@ pub mod pproposal {
                     } else {
                         return Err(Error::DeadLineNotDefined);
                     }
+                }
+                ProposalType::ChangeVotingMode => {
+                    if let Some(voting_mode) = change_voting_mode {
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeVotingMode,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            title,
+                            status: Status::Open,
+                        };
+                        self.proposals.insert(id, &proposal_info);
+                        self.voting_mode_info.insert(id, &voting_mode);
+
+                        let votes = Votes {
+                            yes: 0,
+                            no: 0,
+                            abstain: 0,
+                        };
+                        let voting_status = VotingStatus {
+                            proposal_id: id,
+                            voters: Vec::new(),
+                            votes,
+                        };
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::VotingModeNotDefined);
+                    }
+                }
+                ProposalType::ChangeProjectStatus => {
+                    if let Some(project_status) = change_project_status {
+                        let project_instance: ProjectRef =
+                            ink::env::call::FromAccountId::from_account_id(self.project_address);
+                        if !project_instance.is_status_reachable(project_status) {
+                            return Err(Error::ApproachNotDefined);
+                        }
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeProjectStatus,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            title,
+                            status: Status::Open,
+                        };
+                        self.proposals.insert(id, &proposal_info);
+                        self.project_status_info.insert(id, &project_status);
+
+                        let votes = Votes {
+                            yes: 0,
+                            no: 0,
+                            abstain: 0,
+                        };
+                        let voting_status = VotingStatus {
+                            proposal_id: id,
+                            voters: Vec::new(),
+                            votes,
+                        };
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::ProjectStatusNotDefined);
+                    }
+                }
+                ProposalType::RemoveMember => {
+                    if let Some(removal_info) = remove_member {
+                        let project_instance: ProjectRef =
+                            ink::env::call::FromAccountId::from_account_id(self.project_address);
+                        let replacement_is_member = removal_info
+                            .replacement
+                            .is_none_or(|replacement| project_instance.check_member(replacement));
+                        if !project_instance.check_member(removal_info.member) || !replacement_is_member {
+                            return Err(Error::MemberNotFound);
+                        }
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::RemoveMember,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            title,
+                            status: Status::Open,
+                        };
+                        self.proposals.insert(id, &proposal_info);
+                        self.member_removal_info.insert(id, &removal_info);
+
+                        let votes = Votes {
+                            yes: 0,
+                            no: 0,
+                            abstain: 0,
+                        };
+                        let voting_status = VotingStatus {
+                            proposal_id: id,
+                            voters: Vec::new(),
+                            votes,
+                        };
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::MemberRemovalInfoNotFound);
+                    }
+                }
+                ProposalType::AddTask | ProposalType::UpdateTask | ProposalType::RemoveTask => {
+                    if let Some(task_change) = change_task {
+                        let matches_type = matches!(
+                            (&proposal_type, &task_change),
+                            (ProposalType::AddTask, TaskChange::Add(_))
+                                | (ProposalType::UpdateTask, TaskChange::Update(_))
+                                | (ProposalType::RemoveTask, TaskChange::Remove(_))
+                        );
+                        if !matches_type {
+                            return Err(Error::IncorrectProposalType);
+                        }
+                        // validated again on execution, graph may change meanwhile
+                        self.patch_task_graph(task_change.clone())?;
+                        if let TaskChange::Add(task) | TaskChange::Update(task) = &task_change {
+                            self.check_required_skills(core::slice::from_ref(task))?;
+                        }
+                        if let TaskChange::Add(task) = &task_change {
+                            let project_instance: ProjectRef =
+                                ink::env::call::FromAccountId::from_account_id(self.project_address);
+                            if task.budget > project_instance.get_unbudgeted_fund() {
+                                return Err(Error::InsufficientProjectFund);
+                            }
+                        }
+                        let proposal_info = ProposalInfo {
+                            proposal_type: proposal_type.clone(),
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            title,
+                            status: Status::Open,
+                        };
+                        self.proposals.insert(id, &proposal_info);
+                        self.task_change_info.insert(id, &task_change);
+
+                        let votes = Votes {
+                            yes: 0,
+                            no: 0,
+                            abstain: 0,
+                        };
+                        let voting_status = VotingStatus {
+                            proposal_id: id,
+                            voters: Vec::new(),
+                            votes,
+                        };
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::TaskChangeNotDefined);
+                    }
+                }
+                ProposalType::ChangeManager => {
+                    if let Some(new_manager) = change_manager {
+                        let project_instance: ProjectRef =
+                            ink::env::call::FromAccountId::from_account_id(self.project_address);
+                        // project picks its manager among its members
+                        if !project_instance.check_member(new_manager) {
+                            return Err(Error::MemberNotFound);
+                        }
+                        if project_instance.get_manager() == new_manager {
+                            return Err(Error::AlreadyManager);
+                        }
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeManager,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            title,
+                            status: Status::Open,
+                        };
+                        self.proposals.insert(id, &proposal_info);
+                        self.manager_info.insert(id, &new_manager);
+
+                        let votes = Votes {
+                            yes: 0,
+                            no: 0,
+                            abstain: 0,
+                        };
+                        let voting_status = VotingStatus {
+                            proposal_id: id,
+                            voters: Vec::new(),
+                            votes,
+                        };
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::ManagerInfoNotFound);
+                    }
+                }
+                ProposalType::ApproachCompetition => {
+                    if let Some(tally) = tally_method {
+                        let submission_end = proposal_span.end_time;
+                        // ranked voting runs for another proposal duration once submissions close
+                        let proposal_span = ProposalSpan {
+                            start_time: proposal_span.start_time,
+                            end_time: submission_end + self.proposal_duration,
+                        };
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ApproachCompetition,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            title,
+                            status: Status::Open,
+                        };
+                        self.proposals.insert(id, &proposal_info);
+                        self.competition_info.insert(id, &Competition {
+                            tally,
+                            submission_end,
+                            submitters: Vec::new(),
+                            winner: None,
+                        });
+
+                        let votes = Votes {
+                            yes: 0,
+                            no: 0,
+                            abstain: 0,
+                        };
+                        let voting_status = VotingStatus {
+                            proposal_id: id,
+                            voters: Vec::new(),
+                            votes,
+                        };
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::CompetitionNotDefined);
+                    }
+                }
+                ProposalType::ChangeEarlyResolution => {
+                    if let Some(early_resolution_info) = change_early_resolution {
+                        // ranked ballots are only tallied once voting ends
+                        if early_resolution_info.proposal_type == ProposalType::ApproachCompetition {
+                            return Err(Error::IncorrectProposalType);
+                        }
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeEarlyResolution,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            title,
+                            status: Status::Open,
+                        };
+                        self.proposals.insert(id, &proposal_info);
+                        self.early_resolution_info.insert(id, &early_resolution_info);
+
+                        let votes = Votes {
+                            yes: 0,
+                            no: 0,
+                            abstain: 0,
+                        };
+                        let voting_status = VotingStatus {
+                            proposal_id: id,
+                            voters: Vec::new(),
+                            votes,
+                        };
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::EarlyResolutionInfoNotFound);
+                    }
                 } // _ => return Err(Error::IncorrectProposalType)
             }
+            EmitEvent::<Pproposal>::emit_event(self.env(), ProposalCreated {
+                proposal_id: id,
+                project_address: self.project_address,
+                proposer: caller,
+                proposal_type,
+            });
 
             Ok(id)
         }
@@ -268,18 +707,33 @@ pub mod pproposal {
 
             let project_instance: ProjectRef =
                 ink::env::call::FromAccountId::from_account_id(project_address);
+            let weight = self.member_vote_weight(&project_instance, caller);
 
             match proposal.status {
                 Status::Open => match proposal.proposal_type {
-                    ProposalType::ProposeApproach => {
+                    ProposalType::ProposeApproach
+                    | ProposalType::ChangeVotingMode
+                    | ProposalType::ChangeProjectStatus
+                    | ProposalType::RemoveMember
+                    | ProposalType::AddTask
+                    | ProposalType::UpdateTask
+                    | ProposalType::RemoveTask
+                    | ProposalType::ChangeManager
+                    | ProposalType::ChangeEarlyResolution => {
                         if project_instance.check_member(caller) {
                             let updated_voting_result =
-                                update_voting_result(caller, _curr_voting_result, vote);
+                                update_voting_result(caller, _curr_voting_result, vote, weight);
 
                             match updated_voting_result {
                                 Ok(updated_result) => {
                                     self.voting_result
                                         .insert(proposal.proposal_id, &updated_result);
+                                    EmitEvent::<Pproposal>::emit_event(self.env(), VoteCast {
+                                        proposal_id,
+                                        voter: caller,
+                                        vote,
+                                        weight,
+                                    });
                                 }
                                 Err(err) => return Err(err),
                             }
@@ -295,13 +749,23 @@ pub mod pproposal {
                         ) {
                             Ok(status) => {
                                 if status {
-                                    let updated_voting_result =
-                                        update_voting_result(caller, _curr_voting_result, vote);
+                                    let updated_voting_result = update_voting_result(
+                                        caller,
+                                        _curr_voting_result,
+                                        vote,
+                                        weight,
+                                    );
 
                                     match updated_voting_result {
                                         Ok(updated_result) => {
                                             self.voting_result
                                                 .insert(proposal.proposal_id, &updated_result);
+                                            EmitEvent::<Pproposal>::emit_event(self.env(), VoteCast {
+                                                proposal_id,
+                                                voter: caller,
+                                                vote,
+                                                weight,
+                                            });
                                         }
                                         Err(err) => return Err(err),
                                     }
@@ -312,6 +776,7 @@ pub mod pproposal {
                             Err(err) => return Err(err),
                         }
                     }
+                    ProposalType::ApproachCompetition => return Err(Error::RankedBallotRequired),
                 },
                 Status::Rejected => return Err(Error::ProposalRejected),
                 Status::Passed => {
@@ -320,10 +785,261 @@ pub mod pproposal {
                 }
                 Status::Executed => return Err(Error::ProposalAlreadyExecuted),
             }
+            // this vote may have decided the result already
+            self.update_proposal_open_status(proposal_id)?;
+
+            Ok(())
+        }
+
+        // project members submit their approach while submissions of the competition are open
+        #[ink(message)]
+        pub fn submit_competing_approach(
+            &mut self,
+            proposal_id: u64,
+            approach: AcceptedApproach,
+        ) -> Result<u8> {
+            let caller = self.env().caller();
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
+            let mut competition = self
+                .competition_info
+                .get(proposal_id)
+                .ok_or(Error::CompetitionNotDefined)?;
+            if self.env().block_timestamp() > competition.submission_end {
+                return Err(Error::SubmissionClosed);
+            }
+            let project_instance: ProjectRef =
+                ink::env::call::FromAccountId::from_account_id(self.project_address);
+            if !project_instance.check_member(caller) {
+                return Err(Error::NotAMember);
+            }
+            // one entry per member, so nobody can fill up the competition
+            if competition.submitters.contains(&caller) {
+                return Err(Error::AlreadySubmitted);
+            }
+            if competition.submitters.len() >= MAX_COMPETING_APPROACHES as usize {
+                return Err(Error::TooManyApproaches);
+            }
+            let approach = self.validate_approach(approach, proposal.proposal_span.end_time)?;
 
+            let entry = competition.submitters.len() as u8;
+            competition.submitters.push(caller);
+            self.competing_approaches.insert((proposal_id, entry), &approach);
+            self.competition_info.insert(proposal_id, &competition);
+            EmitEvent::<Pproposal>::emit_event(self.env(), ApproachSubmitted {
+                proposal_id,
+                submitter: caller,
+                entry,
+            });
+            Ok(entry)
+        }
+
+        // ranking holds entry ids from most to least preferred, can be cast once submissions are closed
+        #[ink(message)]
+        pub fn cast_ranked_ballot(&mut self, proposal_id: u64, ranking: Vec<u8>) -> Result<()> {
+            let caller = self.env().caller();
+            let competition = self
+                .competition_info
+                .get(proposal_id)
+                .ok_or(Error::CompetitionNotDefined)?;
+            self.update_proposal_open_status(proposal_id)?;
+            let proposal = self.proposals.get(proposal_id).ok_or(Error::ProposalNotFound)?;
+            match proposal.status {
+                Status::Open => {}
+                Status::Rejected => return Err(Error::ProposalRejected),
+                Status::Passed => return Err(Error::ProposalClosed),
+                Status::Executed => return Err(Error::ProposalAlreadyExecuted),
+            }
+            if self.env().block_timestamp() <= competition.submission_end {
+                return Err(Error::SubmissionRunning);
+            }
+            let project_instance: ProjectRef =
+                ink::env::call::FromAccountId::from_account_id(self.project_address);
+            if !project_instance.check_member(caller) {
+                return Err(Error::NotAMember);
+            }
+            let entries = competition.submitters.len();
+            let unique: BTreeSet<u8> = ranking.iter().copied().collect();
+            if ranking.is_empty()
+                || unique.len() != ranking.len()
+                || ranking.iter().any(|entry| *entry as usize >= entries)
+            {
+                return Err(Error::InvalidBallot);
+            }
+            let mut ballots = self.ranked_ballots.get(proposal_id).unwrap_or_default();
+            if ballots.iter().any(|ballot| ballot.voter == caller) {
+                return Err(Error::AlreadyVoted);
+            }
+            let weight = self.member_vote_weight(&project_instance, caller);
+            ballots.push(RankedBallot {
+                voter: caller,
+                ranking,
+                weight,
+            });
+            self.ranked_ballots.insert(proposal_id, &ballots);
+            EmitEvent::<Pproposal>::emit_event(self.env(), RankedBallotCast {
+                proposal_id,
+                voter: caller,
+                weight,
+            });
             Ok(())
         }
 
+        // project members don't hold governance token, token weighted mode counts equally here
+        fn member_vote_weight(&self, project_instance: &ProjectRef, member: AccountId) -> u64 {
+            match self.voting_mode {
+                VotingMode::Equal | VotingMode::TokenWeighted => 1,
+                VotingMode::RatingWeighted => project_instance.get_member_vote_weight(member),
+            }
+        }
+
+        // required skills of tasks should be registered in the organisation(called by selector, org depends on this crate)
+        fn check_required_skills(&self, tasks: &[Task]) -> Result<()> {
+            let mut skills: Vec<u16> = tasks
+                .iter()
+                .flat_map(|task| task.required_skills.iter().copied())
+                .collect();
+            if skills.is_empty() {
+                return Ok(());
+            }
+            skills.sort_unstable();
+            skills.dedup();
+            let project_instance: ProjectRef =
+                ink::env::call::FromAccountId::from_account_id(self.project_address);
+            build_call::<DefaultEnvironment>()
+                .call(project_instance.get_organisation())
+                .gas_limit(0)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("check_skill_tags")))
+                        .push_arg(skills),
+                )
+                .returns::<Result<()>>()
+                .invoke()
+        }
+
+        // checks approach against project fund and works out its task graph
+        fn validate_approach(
+            &self,
+            mut approach: AcceptedApproach,
+            voting_end: Timestamp,
+        ) -> Result<AcceptedApproach> {
+            if approach.project_start_date < voting_end {
+                return Err(Error::CannotStartProjectBeforeVoting);
+            }
+
+            for task in approach.tasks.iter() {
+                if task.end_time > approach.project_completion_date {
+                    return Err(Error::TaskDeadlineCannotCrossPojectDeadLine);
+                }
+            }
+            self.check_required_skills(&approach.tasks)?;
+            // update task list with graph node informations
+            let (tasks, root_tasks) = build_task_graph(approach.tasks)?;
+            approach.tasks = tasks;
+            approach.root_tasks = root_tasks;
+
+            // budgets are kept in escrow by project, so they should fit in the project fund
+            for milestone in approach.milestones.iter() {
+                let unknown_task = milestone
+                    .task_ids
+                    .iter()
+                    .any(|id| !approach.tasks.iter().any(|task| task.task_id == *id));
+                if milestone.task_ids.is_empty() || unknown_task {
+                    return Err(Error::InvalidMilestone);
+                }
+            }
+            let escrow = approach_escrow(&approach)?;
+            let project_instance: ProjectRef =
+                ink::env::call::FromAccountId::from_account_id(self.project_address);
+            if escrow > project_instance.get_unbudgeted_fund() {
+                return Err(Error::InsufficientProjectFund);
+            }
+            Ok(approach)
+        }
+
+        // tallies the ranked ballots, winning approach becomes the approach of the proposal
+        fn close_competition(&mut self, proposal_id: u64, total_voters: u64) -> bool {
+            let mut competition = match self.competition_info.get(proposal_id) {
+                Some(competition) => competition,
+                None => return false,
+            };
+            let ballots = self.ranked_ballots.get(proposal_id).unwrap_or_default();
+            // Till 6 digit after decimal
+            let factor: u64 = 1_000_000;
+            let cast_weight: u64 = ballots.iter().map(|ballot| ballot.weight).sum();
+            if total_voters == 0 || (cast_weight * factor) / total_voters < self.quorum {
+                return false;
+            }
+            let entries = competition.submitters.len();
+            let winner = match competition.tally {
+                TallyMethod::InstantRunoff => instant_runoff_winner(&ballots, entries),
+                TallyMethod::Borda => borda_winner(&ballots, entries),
+            };
+            let approach = winner.and_then(|entry| self.competing_approaches.get((proposal_id, entry)));
+            if let Some(approach) = approach {
+                competition.winner = winner;
+                self.competition_info.insert(proposal_id, &competition);
+                self.approach_info.insert(proposal_id, &approach);
+                return true;
+            }
+            false
+        }
+
+        // applies the change on current task graph of the project and validates the result
+        fn patch_task_graph(&self, task_change: TaskChange) -> Result<(Vec<Task>, Vec<u16>)> {
+            let project_instance: ProjectRef =
+                ink::env::call::FromAccountId::from_account_id(self.project_address);
+            let deadline = project_instance.get_deadline().ok_or(Error::ApproachNotDefined)?;
+            let mut tasks = project_instance.get_tasks();
+            match task_change {
+                TaskChange::Add(mut task) => {
+                    task.status = TaskStatus::NotYetStarted;
+                    task.performance_rating = None;
+                    tasks.push(task);
+                }
+                TaskChange::Update(mut task) => {
+                    let index = tasks
+                        .iter()
+                        .position(|old_task| old_task.task_id == task.task_id)
+                        .ok_or(Error::TaskNotFound)?;
+                    let old_task = &tasks[index];
+                    if old_task.status == TaskStatus::Completed {
+                        return Err(Error::TaskCompleted);
+                    }
+                    task.status = old_task.status.clone();
+                    task.performance_rating = old_task.performance_rating;
+                    task.budget = old_task.budget;
+                    // deadline only moves through ExtendDeadLine
+                    task.end_time = old_task.end_time;
+                    task.deadline_policy = old_task.deadline_policy;
+                    tasks[index] = task;
+                }
+                TaskChange::Remove(task_id) => {
+                    let index = tasks
+                        .iter()
+                        .position(|task| task.task_id == task_id)
+                        .ok_or(Error::TaskNotFound)?;
+                    let removed_task = tasks.remove(index);
+                    if removed_task.status == TaskStatus::Completed {
+                        return Err(Error::TaskCompleted);
+                    } else if removed_task.status == TaskStatus::InReview {
+                        return Err(Error::TaskInReview);
+                    }
+                    for task in tasks.iter_mut() {
+                        if task.start_after.contains(&task_id) {
+                            task.start_after.retain(|id| *id != task_id);
+                            task.start_after.extend(removed_task.start_after.iter());
+                        }
+                    }
+                }
+            }
+            for task in tasks.iter() {
+                if task.end_time > deadline.project_completion_date {
+                    return Err(Error::TaskDeadlineCannotCrossPojectDeadLine);
+                }
+            }
+            build_task_graph(tasks)
+        }
+
         fn update_proposal_open_status(&mut self, proposal_id: u64) -> Result<()> {
             let mut proposal = self.proposals.get(proposal_id).unwrap();
             let curr_voting_result = self.voting_result.get(proposal_id).unwrap();
@@ -332,24 +1048,55 @@ pub mod pproposal {
             let project_instance: ProjectRef =
                 ink::env::call::FromAccountId::from_account_id(project_address);
 
-            if self.env().block_timestamp() > proposal.proposal_span.end_time {
+            let expired = self.env().block_timestamp() > proposal.proposal_span.end_time;
+            let early_resolution = self
+                .early_resolution
+                .get(&proposal.proposal_type)
+                .unwrap_or(false);
+            if expired || early_resolution {
                 // Implement better voting result algo
-                let total_voters = project_instance.total_members();
+                let total_voters = match self.voting_mode {
+                    VotingMode::Equal | VotingMode::TokenWeighted => project_instance.total_members(),
+                    VotingMode::RatingWeighted => project_instance.total_vote_weight(),
+                };
                 match proposal.status {
                     Status::Open => {
                         // Till 6 digit after decimal
                         let factor: u64 = 1_000_000;
-                        let voted_quorum: u64 =
-                            (curr_voting_result.voters.len() as u64 * factor) / total_voters;
-                        if curr_voting_result.votes.no <= curr_voting_result.votes.yes
-                            && (self.quorum) <= voted_quorum
-                        {
+                        let votes = &curr_voting_result.votes;
+                        let cast = votes.yes + votes.no + votes.abstain;
+                        let voted_quorum: u64 = cast.saturating_mul(factor).checked_div(total_voters).unwrap_or(0);
+                        // nobody is able to vote when project has no members
+                        let passed = if total_voters == 0 {
+                            false
+                        } else if proposal.proposal_type == ProposalType::ApproachCompetition {
+                            self.close_competition(proposal_id, total_voters)
+                        } else if expired {
+                            votes.no <= votes.yes && (self.quorum) <= voted_quorum
+                        } else {
+                            // closed early only if uncast votes all going one way can't flip the result
+                            let uncast = total_voters.saturating_sub(cast);
+                            if votes.no + uncast <= votes.yes && (self.quorum) <= voted_quorum {
+                                true
+                            } else if votes.yes + uncast < votes.no {
+                                // missing quorum would reject it as well
+                                false
+                            } else {
+                                return Ok(());
+                            }
+                        };
+                        if passed {
                             proposal.status = Status::Passed;
                             // call execute function to execute the call from here
                         } else {
                             proposal.status = Status::Rejected;
                         }
                         self.proposals.insert(proposal.proposal_id, &proposal);
+                        EmitEvent::<Pproposal>::emit_event(self.env(), ProposalStatusChanged {
+                            proposal_id,
+                            project_address,
+                            status: proposal.status,
+                        });
                         return Ok(());
                     }
                     _ => {}
@@ -386,7 +1133,8 @@ pub mod pproposal {
                     // contains code for executing passed proposals
                     Status::Passed => {
                         match proposal.proposal_type {
-                            ProposalType::ProposeApproach => {
+                            // winner of the competition is stored as the approach of the proposal
+                            ProposalType::ProposeApproach | ProposalType::ApproachCompetition => {
                                 let approach =
                                     self.approach_info.get(proposal.proposal_id).unwrap();
 
@@ -407,10 +1155,67 @@ pub mod pproposal {
                                         extension_info.deadline,
                                     )
                                     .unwrap();
+                                proposal.status = Status::Executed;
+                                self.proposals.insert(proposal.proposal_id, &proposal);
+                            }
+                            ProposalType::ChangeVotingMode => {
+                                let voting_mode =
+                                    self.voting_mode_info.get(proposal.proposal_id).unwrap();
+
+                                self.voting_mode = voting_mode;
+                                proposal.status = Status::Executed;
+                                self.proposals.insert(proposal.proposal_id, &proposal);
+                            }
+                            ProposalType::ChangeProjectStatus => {
+                                let project_status =
+                                    self.project_status_info.get(proposal.proposal_id).unwrap();
+                                project_instance.update_project_status(project_status)?;
+                                proposal.status = Status::Executed;
+                                self.proposals.insert(proposal.proposal_id, &proposal);
+                            }
+                            ProposalType::RemoveMember => {
+                                let removal_info =
+                                    self.member_removal_info.get(proposal.proposal_id).unwrap();
+                                project_instance
+                                    .remove_project_member(removal_info.member, removal_info.replacement)?;
+                                proposal.status = Status::Executed;
+                                self.proposals.insert(proposal.proposal_id, &proposal);
+                            }
+                            ProposalType::AddTask
+                            | ProposalType::UpdateTask
+                            | ProposalType::RemoveTask => {
+                                let task_change =
+                                    self.task_change_info.get(proposal.proposal_id).unwrap();
+                                let (tasks, root_tasks) = self.patch_task_graph(task_change)?;
+                                project_instance.try_update_task_graph(tasks, root_tasks)?;
+                                proposal.status = Status::Executed;
+                                self.proposals.insert(proposal.proposal_id, &proposal);
+                            }
+                            ProposalType::ChangeManager => {
+                                let new_manager = self.manager_info.get(proposal.proposal_id).unwrap();
+                                project_instance.change_manager(new_manager)?;
+                                proposal.status = Status::Executed;
+                                self.proposals.insert(proposal.proposal_id, &proposal);
+                            }
+                            ProposalType::ChangeEarlyResolution => {
+                                let early_resolution_info =
+                                    self.early_resolution_info.get(proposal.proposal_id).unwrap();
+
+                                self.early_resolution.insert(
+                                    early_resolution_info.proposal_type,
+                                    &early_resolution_info.enabled,
+                                );
+                                proposal.status = Status::Executed;
+                                self.proposals.insert(proposal.proposal_id, &proposal);
                             } // _ => {
                               //     return Err(Error::UnAuthorized);
                               // }
                         }
+                        EmitEvent::<Pproposal>::emit_event(self.env(), ProposalStatusChanged {
+                            proposal_id,
+                            project_address,
+                            status: Status::Executed,
+                        });
                     }
                     Status::Executed => {
                         return Err(Error::ProposalAlreadyExecuted);
@@ -460,6 +1265,91 @@ pub mod pproposal {
             }
         }
         #[ink(message)]
+        pub fn get_early_resolution_info(&self, proposal_id: u64) -> Result<EarlyResolutionInfo> {
+            let early_resolution_info = self.early_resolution_info.get(proposal_id);
+            if let Some(info) = early_resolution_info {
+                return Ok(info);
+            } else {
+                return Err(Error::EarlyResolutionInfoNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn is_early_resolution_enabled(&self, proposal_type: ProposalType) -> bool {
+            self.early_resolution.get(proposal_type).unwrap_or(false)
+        }
+        #[ink(message)]
+        pub fn get_competition_info(&self, proposal_id: u64) -> Result<Competition> {
+            let competition = self.competition_info.get(proposal_id);
+            if let Some(info) = competition {
+                return Ok(info);
+            } else {
+                return Err(Error::CompetitionNotDefined);
+            }
+        }
+        #[ink(message)]
+        pub fn get_competing_approach(&self, proposal_id: u64, entry: u8) -> Result<AcceptedApproach> {
+            self.competing_approaches
+                .get((proposal_id, entry))
+                .ok_or(Error::ApproachNotDefined)
+        }
+        #[ink(message)]
+        pub fn get_ranked_ballots(&self, proposal_id: u64) -> Vec<RankedBallot> {
+            self.ranked_ballots.get(proposal_id).unwrap_or_default()
+        }
+        #[ink(message)]
+        pub fn get_voting_mode(&self) -> VotingMode {
+            self.voting_mode
+        }
+        #[ink(message)]
+        pub fn get_voting_mode_info(&self, proposal_id: u64) -> Result<VotingMode> {
+            let voting_mode_info = self.voting_mode_info.get(proposal_id);
+            if let Some(info) = voting_mode_info {
+                return Ok(info);
+            } else {
+                return Err(Error::VotingModeNotDefined);
+            }
+        }
+        #[ink(message)]
+        pub fn get_project_status_info(&self, proposal_id: u64) -> Result<ProjectStatus> {
+            let project_status_info = self.project_status_info.get(proposal_id);
+            if let Some(info) = project_status_info {
+                return Ok(info);
+            } else {
+                return Err(Error::ProjectStatusNotDefined);
+            }
+        }
+        #[ink(message)]
+        pub fn get_manager_info(&self, proposal_id: u64) -> Result<AccountId> {
+            let manager_info = self.manager_info.get(proposal_id);
+            if let Some(info) = manager_info {
+                return Ok(info);
+            } else {
+                return Err(Error::ManagerInfoNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn get_task_change_info(&self, proposal_id: u64) -> Result<TaskChange> {
+            let task_change = self.task_change_info.get(proposal_id);
+            if let Some(info) = task_change {
+                return Ok(info);
+            } else {
+                return Err(Error::TaskChangeNotDefined);
+            }
+        }
+        #[ink(message)]
+        pub fn get_member_removal_info(&self, proposal_id: u64) -> Result<MemberRemovalInfo> {
+            let removal_info = self.member_removal_info.get(proposal_id);
+            if let Some(info) = removal_info {
+                return Ok(info);
+            } else {
+                return Err(Error::MemberRemovalInfoNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn get_deadline_overrun(&self, proposal_id: u64) -> Option<Timestamp> {
+            self.deadline_overrun.get(proposal_id)
+        }
+        #[ink(message)]
         pub fn get_deadline_extension_info(
             &self,
             proposal_id: u64,
@@ -475,77 +1365,163 @@ pub mod pproposal {
     // Helper Functions     Helper Functions    Helper Functions    Helper Functions
     // Helper Functions     Helper Functions    Helper Functions    Helper Functions
 
-    fn create_task_nodes(
-        task_by_id: &mut Mapping<u16, Task>,
-        task_list: &mut Vec<Task>,
-        mut task: Task,
-    ) {
-        let mut start_after_map: Mapping<u16, bool> = Mapping::new();
-        let user_start_after = task.clone().start_after;
-
-        // if user_start_after.is_empty() {
-        //     independent_tasks.push(task.clone().task_id);
-        //     task_by_id.insert(task.task_id, &task.clone());
-        // }
-
-        user_start_after.iter().map(|id| {
-            start_after_map.insert(id, &true);
-        });
-
-        let mut actual_start_after: Vec<u16> = Vec::new();
-        for node in user_start_after {
-            if !find_child_node(task_by_id, node, &start_after_map) {
-                actual_start_after.push(node);
-                let node_task = task_by_id.get(node);
-                if let Some(mut node_task) = node_task {
-                    node_task.children_task.push(task.task_id);
-                    task_by_id.insert(node_task.task_id, &node_task);
+    /// validates the dependency graph of the proposed tasks and fills children_task of every task
+    /// dependencies already implied by another dependency are dropped from start_after
+    /// returns the tasks in the given order along with the tasks which don't depend on any other task
+    fn build_task_graph(tasks: Vec<Task>) -> Result<(Vec<Task>, Vec<u16>)> {
+        let mut task_ids: Vec<u16> = Vec::new();
+        let mut task_by_id: BTreeMap<u16, Task> = BTreeMap::new();
+        for mut task in tasks {
+            if let Some(start_time) = task.start_time {
+                if task.end_time < start_time {
+                    return Err(Error::TaskEndsBeforeStart);
+                }
+            }
+            if task_by_id.contains_key(&task.task_id) {
+                return Err(Error::DuplicateTaskId);
+            }
+            task.start_after.sort_unstable();
+            task.start_after.dedup();
+            task.children_task = Vec::new();
+            task.required_skills = normalize_skills(task.required_skills)?;
+            task_ids.push(task.task_id);
+            task_by_id.insert(task.task_id, task);
+        }
+
+        let mut pending_parents: BTreeMap<u16, usize> = BTreeMap::new();
+        let mut children: BTreeMap<u16, Vec<u16>> = BTreeMap::new();
+        for task in task_by_id.values() {
+            for parent in task.start_after.iter() {
+                if *parent == task.task_id {
+                    return Err(Error::TaskDependencyCycle);
+                }
+                if !task_by_id.contains_key(parent) {
+                    return Err(Error::UnknownDependency);
+                }
+                children.entry(*parent).or_default().push(task.task_id);
+            }
+            pending_parents.insert(task.task_id, task.start_after.len());
+        }
+
+        // kahn's algorithm, tasks left out of the ordering are part of a cycle
+        let root_tasks: Vec<u16> = task_ids
+            .iter()
+            .filter(|id| pending_parents[*id] == 0)
+            .copied()
+            .collect();
+        let mut ordered = root_tasks.clone();
+        let mut next = 0;
+        while next < ordered.len() {
+            let u = ordered[next];
+            next += 1;
+            for child in children.get(&u).into_iter().flatten() {
+                let remaining = pending_parents.get_mut(child).unwrap();
+                *remaining -= 1;
+                if *remaining == 0 {
+                    ordered.push(*child);
                 }
             }
         }
-        task.start_after = actual_start_after;
-        task_by_id.insert(task.task_id, &task.clone());
-        task_list.push(task);
+        if ordered.len() != task_ids.len() {
+            return Err(Error::TaskDependencyCycle);
+        }
+
+        // parents come before children in the ordering, so ancestors of every parent are known
+        let mut ancestors: BTreeMap<u16, BTreeSet<u16>> = BTreeMap::new();
+        for id in ordered.iter() {
+            let start_after = task_by_id[id].start_after.clone();
+            let mut task_ancestors: BTreeSet<u16> = BTreeSet::new();
+            for parent in start_after.iter() {
+                task_ancestors.insert(*parent);
+                task_ancestors.extend(ancestors[parent].iter());
+            }
+            let actual_start_after: Vec<u16> = start_after
+                .iter()
+                .filter(|parent| {
+                    !start_after
+                        .iter()
+                        .any(|other| ancestors[other].contains(parent))
+                })
+                .copied()
+                .collect();
+            for parent in actual_start_after.iter() {
+                task_by_id.get_mut(parent).unwrap().children_task.push(*id);
+            }
+            task_by_id.get_mut(id).unwrap().start_after = actual_start_after;
+            ancestors.insert(*id, task_ancestors);
+        }
+
+        let tasks = task_ids
+            .iter()
+            .map(|id| task_by_id.remove(id).unwrap())
+            .collect();
+        Ok((tasks, root_tasks))
     }
 
-    // bfs, check if any child is also in the element
-    fn find_child_node(
-        task_by_id: &Mapping<u16, Task>,
-        node: u16,
-        elements: &Mapping<u16, bool>,
-    ) -> bool {
-        let mut vis: Mapping<u16, bool> = Mapping::new();
-        let mut queue: Vec<u16> = Vec::new();
-        queue.push(node);
-        vis.insert(node, &true);
-
-        while !queue.is_empty() {
-            let u = queue[0];
-            queue.remove(0);
-            let u_task_node = task_by_id.get(u);
-
-            if let Some(u_task) = u_task_node {
-                let neighbour = u_task.children_task;
-
-                for id in neighbour {
-                    if vis.get(id).is_none() {
-                        if elements.get(id).is_some() {
-                            return true;
-                        } else {
-                            vis.insert(id, &true);
-                            queue.push(id);
-                        }
-                    }
+    // approach with least first preferences is eliminated till one holds majority of the live ballots,
+    // on a tie the later submission is eliminated
+    fn instant_runoff_winner(ballots: &[RankedBallot], entries: usize) -> Option<u8> {
+        let mut eliminated: Vec<bool> = ink::prelude::vec![false; entries];
+        let mut remaining = entries;
+        while remaining > 0 {
+            let mut counts: Vec<u64> = ink::prelude::vec![0; entries];
+            let mut live_weight: u64 = 0;
+            for ballot in ballots.iter() {
+                let preference = ballot
+                    .ranking
+                    .iter()
+                    .find(|entry| !eliminated[**entry as usize]);
+                if let Some(entry) = preference {
+                    counts[*entry as usize] += ballot.weight;
+                    live_weight += ballot.weight;
+                }
+            }
+            if live_weight == 0 {
+                return None;
+            }
+            let mut leader: Option<usize> = None;
+            let mut trailing: Option<usize> = None;
+            for entry in (0..entries).filter(|entry| !eliminated[*entry]) {
+                if leader.is_none_or(|leader| counts[entry] > counts[leader]) {
+                    leader = Some(entry);
                 }
+                if trailing.is_none_or(|trailing| counts[entry] <= counts[trailing]) {
+                    trailing = Some(entry);
+                }
+            }
+            let leader = leader?;
+            if counts[leader] * 2 > live_weight || remaining == 1 {
+                return Some(leader as u8);
+            }
+            eliminated[trailing?] = true;
+            remaining -= 1;
+        }
+        None
+    }
+
+    // approach ranked at position p earns (entries - 1 - p) points for every unit of ballot weight,
+    // on a tie the earlier submission wins
+    fn borda_winner(ballots: &[RankedBallot], entries: usize) -> Option<u8> {
+        let mut points: Vec<u64> = ink::prelude::vec![0; entries];
+        for ballot in ballots.iter() {
+            for (position, entry) in ballot.ranking.iter().enumerate() {
+                points[*entry as usize] += (entries - 1 - position) as u64 * ballot.weight;
+            }
+        }
+        let mut winner: Option<usize> = None;
+        for entry in 0..entries {
+            if winner.is_none_or(|winner| points[entry] > points[winner]) {
+                winner = Some(entry);
             }
         }
-        false
+        winner.map(|entry| entry as u8)
     }
 
     fn update_voting_result(
         caller: AccountId,
         mut curr_voting_result: VotingStatus,
         vote: Vote,
+        weight: u64,
     ) -> Result<VotingStatus> {
         let voterinfo = curr_voting_result
             .voters
@@ -557,22 +1533,190 @@ pub mod pproposal {
             let voter_info = VoteInfo {
                 voter: caller,
                 vote: vote.clone(),
+                weight,
             };
             curr_voting_result.voters.push(voter_info);
 
             match vote.clone() {
                 Vote::Yes => {
-                    curr_voting_result.votes.yes += 1;
+                    curr_voting_result.votes.yes += weight;
                 }
                 Vote::No => {
-                    curr_voting_result.votes.no += 1;
+                    curr_voting_result.votes.no += weight;
                 }
                 Vote::Abstain => {
-                    curr_voting_result.votes.abstain += 1;
+                    curr_voting_result.votes.abstain += weight;
                 }
             }
 
             Ok(curr_voting_result)
         }
     }
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use project::DeadlinePolicy;
+
+        fn ballot(voter: u8, ranking: &[u8], weight: u64) -> RankedBallot {
+            RankedBallot {
+                voter: AccountId::from([voter; 32]),
+                ranking: ranking.to_vec(),
+                weight,
+            }
+        }
+
+        #[test]
+        fn instant_runoff_majority_on_first_round() {
+            let ballots = [ballot(1, &[0, 1], 1), ballot(2, &[0], 1), ballot(3, &[1, 0], 1)];
+            assert_eq!(instant_runoff_winner(&ballots, 2), Some(0));
+        }
+
+        #[test]
+        fn instant_runoff_transfers_eliminated_preferences() {
+            // entry 2 has least first preferences, its ballot moves on to entry 1
+            let ballots = [
+                ballot(1, &[0], 2),
+                ballot(2, &[1], 2),
+                ballot(3, &[2, 1], 1),
+            ];
+            assert_eq!(instant_runoff_winner(&ballots, 3), Some(1));
+        }
+
+        #[test]
+        fn instant_runoff_eliminates_later_entry_on_tie() {
+            let ballots = [ballot(1, &[0], 1), ballot(2, &[1], 1)];
+            assert_eq!(instant_runoff_winner(&ballots, 2), Some(0));
+        }
+
+        #[test]
+        fn instant_runoff_drops_exhausted_ballots() {
+            // ballot ranking only entry 2 is exhausted after its elimination
+            let ballots = [
+                ballot(1, &[0], 2),
+                ballot(2, &[1, 0], 1),
+                ballot(3, &[2], 1),
+                ballot(4, &[1], 1),
+            ];
+            assert_eq!(instant_runoff_winner(&ballots, 3), Some(0));
+        }
+
+        #[test]
+        fn instant_runoff_without_ballots() {
+            assert_eq!(instant_runoff_winner(&[], 3), None);
+        }
+
+        #[test]
+        fn borda_counts_positions() {
+            // entry 1 is everyone's second choice and collects most points
+            let ballots = [
+                ballot(1, &[0, 1, 2], 1),
+                ballot(2, &[2, 1, 0], 1),
+                ballot(3, &[1, 0, 2], 1),
+            ];
+            assert_eq!(borda_winner(&ballots, 3), Some(1));
+        }
+
+        #[test]
+        fn borda_weights_ballots() {
+            let ballots = [ballot(1, &[0, 1], 1), ballot(2, &[1, 0], 3)];
+            assert_eq!(borda_winner(&ballots, 2), Some(1));
+        }
+
+        #[test]
+        fn borda_earlier_entry_wins_tie() {
+            let ballots = [ballot(1, &[1, 0], 1), ballot(2, &[0, 1], 1)];
+            assert_eq!(borda_winner(&ballots, 2), Some(0));
+        }
+
+        #[test]
+        fn borda_partial_ranking_gives_no_points_to_unranked() {
+            let ballots = [ballot(1, &[2], 1), ballot(2, &[0, 1], 1)];
+            // entry 0 and entry 2 both get 2 points, earlier submission wins
+            assert_eq!(borda_winner(&ballots, 3), Some(0));
+        }
+
+        fn task(task_id: u16, start_after: &[u16]) -> Task {
+            Task {
+                task_id,
+                start_after: start_after.to_vec(),
+                start_time: None,
+                end_time: 10,
+                status: TaskStatus::NotYetStarted,
+                performance_rating: None,
+                details: Vec::new(),
+                children_task: Vec::new(),
+                budget: 0,
+                deadline_policy: DeadlinePolicy::Rigid,
+                required_skills: Vec::new(),
+            }
+        }
+
+        #[test]
+        fn task_graph_links_children_and_finds_roots() {
+            let tasks = vec![task(0, &[]), task(1, &[0]), task(2, &[0]), task(3, &[1, 2]), task(4, &[])];
+            let (tasks, root_tasks) = build_task_graph(tasks).unwrap();
+            assert_eq!(root_tasks, vec![0, 4]);
+            assert_eq!(tasks[0].children_task, vec![1, 2]);
+            assert_eq!(tasks[3].start_after, vec![1, 2]);
+            assert!(tasks[3].children_task.is_empty());
+        }
+
+        #[test]
+        fn task_graph_prunes_implied_dependencies() {
+            // 2 already starts after 0 through 1, and the duplicate entry of 1 is dropped
+            let tasks = vec![task(0, &[]), task(1, &[0]), task(2, &[1, 0, 1])];
+            let (tasks, root_tasks) = build_task_graph(tasks).unwrap();
+            assert_eq!(root_tasks, vec![0]);
+            assert_eq!(tasks[2].start_after, vec![1]);
+            assert_eq!(tasks[0].children_task, vec![1]);
+            assert_eq!(tasks[1].children_task, vec![2]);
+        }
+
+        #[test]
+        fn task_graph_prunes_transitive_dependency_over_longer_chain() {
+            let tasks = vec![task(3, &[0, 2]), task(2, &[1]), task(1, &[0]), task(0, &[])];
+            let (tasks, _) = build_task_graph(tasks).unwrap();
+            assert_eq!(tasks[0].task_id, 3);
+            assert_eq!(tasks[0].start_after, vec![2]);
+            assert_eq!(tasks[3].children_task, vec![1]);
+        }
+
+        #[test]
+        fn task_graph_rejects_duplicate_task_id() {
+            let tasks = vec![task(0, &[]), task(0, &[])];
+            assert!(matches!(build_task_graph(tasks), Err(Error::DuplicateTaskId)));
+        }
+
+        #[test]
+        fn task_graph_rejects_unknown_dependency() {
+            let tasks = vec![task(0, &[]), task(1, &[5])];
+            assert!(matches!(build_task_graph(tasks), Err(Error::UnknownDependency)));
+        }
+
+        #[test]
+        fn task_graph_rejects_self_dependency() {
+            let tasks = vec![task(0, &[0])];
+            assert!(matches!(build_task_graph(tasks), Err(Error::TaskDependencyCycle)));
+        }
+
+        #[test]
+        fn task_graph_rejects_longer_cycle() {
+            let tasks = vec![task(0, &[]), task(1, &[0, 3]), task(2, &[1]), task(3, &[2])];
+            assert!(matches!(build_task_graph(tasks), Err(Error::TaskDependencyCycle)));
+        }
+
+        #[test]
+        fn task_graph_rejects_task_ending_before_start() {
+            let mut late_start = task(0, &[]);
+            late_start.start_time = Some(11);
+            assert!(matches!(build_task_graph(vec![late_start]), Err(Error::TaskEndsBeforeStart)));
+        }
+
+        #[test]
+        fn task_graph_rejects_too_many_skill_tags() {
+            let mut skilled = task(0, &[]);
+            skilled.required_skills = (0..=project::MAX_SKILL_TAGS as u16).collect();
+            assert!(matches!(build_task_graph(vec![skilled]), Err(Error::TooManySkillTags)));
+        }
+    }
 }
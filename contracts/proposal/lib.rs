@@ -5,13 +5,20 @@ pub use self::proposal::{Proposal, ProposalRef};
 #[ink::contract]
 pub mod proposal {
 
+    use ink::codegen::EmitEvent;
     use ink::prelude::string::String;
+    use ink::prelude::vec;
     use ink::prelude::vec::Vec;
     use ink::storage::traits::StorageLayout;
     use ink::storage::Mapping;
 
     use org::{Designation, OrgRef};
-    use project::{ProjectRef,Error,Result};
+    use project::{ProjectRef,Error,ProjectStatus,Result,VotingMode};
+
+    /// maximum length of a delegation chain(delegator -> delegate -> ...)
+    const MAX_DELEGATION_DEPTH: u32 = 5;
+    /// upper bound on the execution timelock(30 days in millisecond), keeps ChangeTimelock itself executable
+    const MAX_TIMELOCK: Timestamp = 2_592_000_000;
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
@@ -20,6 +27,16 @@ pub mod proposal {
         FundProject,
         ChangeQuorum,
         ChangeProposalSpan,
+        ChangeVotingMode,
+        AddMember,
+        RemoveMember,
+        PromoteToSecretariate,
+        DemoteSecretariate,
+        MintTokens,
+        CloseProject,
+        ChangeManager,
+        ChangeEarlyResolution,
+        ChangeTimelock,
     }
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
@@ -28,6 +45,8 @@ pub mod proposal {
         Rejected,
         Passed,
         Executed,
+        /// cancelled by the veto body while timelocked
+        Vetoed,
     }
 
     #[derive(Debug, Copy, PartialEq, Eq, scale::Encode, scale::Decode, Clone)]
@@ -58,6 +77,7 @@ pub mod proposal {
     pub struct VoteInfo {
         voter: AccountId,
         vote: Vote,
+        weight: u64,
     }
     #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
@@ -105,6 +125,63 @@ pub mod proposal {
         duration: u64,
     }
 
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// name, company_id and rating are only used while adding or promoting a member
+    pub struct MembershipInfo {
+        member_address: AccountId,
+        name: String,
+        company_id: u16,
+        rating: Option<u8>,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct MintInfo {
+        member_address: AccountId,
+        amount: u128,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// status should be either Completed or Cancelled
+    pub struct CloseProjectInfo {
+        project_id: u32,
+        status: ProjectStatus,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// manager should be a member of organisation
+    pub struct ChangeManagerInfo {
+        project_id: u32,
+        manager: AccountId,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// proposals of this type are closed before their end time once uncast votes can't change the result
+    pub struct EarlyResolutionInfo {
+        proposal_type: ProposalType,
+        enabled: bool,
+    }
+
+    #[derive(Debug, Copy, PartialEq, Eq, scale::Encode, scale::Decode, Clone)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum VetoBody {
+        Owner,
+        /// two third of the secretariates
+        Secretariate,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    /// passed proposals wait for duration before being executable, veto body can cancel them meanwhile
+    pub struct TimelockInfo {
+        duration: Timestamp,
+        veto_body: VetoBody,
+    }
+
     #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub struct ProposalInfo {
@@ -118,6 +195,48 @@ pub mod proposal {
         status: Status,
     }
 
+    #[ink(event)]
+    pub struct ProposalCreated {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        proposer: AccountId,
+        proposal_type: ProposalType,
+    }
+
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        voter: AccountId,
+        vote: Vote,
+        weight: u64,
+    }
+
+    #[ink(event)]
+    pub struct ProposalStatusChanged {
+        #[ink(topic)]
+        proposal_id: u64,
+        status: Status,
+    }
+
+    #[ink(event)]
+    pub struct VetoCast {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        voter: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct DelegationChanged {
+        #[ink(topic)]
+        delegator: AccountId,
+        #[ink(topic)]
+        delegate: Option<AccountId>,
+    }
+
     #[ink(storage)]
     pub struct Proposal {
         next_proposal_id: u64,
@@ -131,6 +250,8 @@ pub mod proposal {
         proposal_duration: Timestamp,
         // net voting should be checked. If voting is less than this quorum number then reject proposal after expiratiion period(it is a percentage value)
         quorum: u64, // stored as actual * 1_000_000
+        // weighted voting uses rating of members stored in organisation
+        voting_mode: VotingMode,
         // store org_contract address to execute operation based on that organistion
         org_address: AccountId,
         // store project created id (proposal id -> project id)
@@ -141,12 +262,38 @@ pub mod proposal {
         fund_project_info: Mapping<u64, FundProjectInfo>,
         quorum_info: Mapping<u64, QuorumInfo>,
         span_info: Mapping<u64, SpanInfo>,
+        voting_mode_info: Mapping<u64, VotingMode>,
+        membership_info: Mapping<u64, MembershipInfo>,
+        mint_info: Mapping<u64, MintInfo>,
+        close_project_info: Mapping<u64, CloseProjectInfo>,
+        change_manager_info: Mapping<u64, ChangeManagerInfo>,
+        early_resolution_info: Mapping<u64, EarlyResolutionInfo>,
+        /// proposal types allowed to be resolved before their end time
+        early_resolution: Mapping<ProposalType, bool>,
+        /// delay between passing and execution of a proposal
+        timelock: Timestamp,
+        veto_body: VetoBody,
+        timelock_info: Mapping<u64, TimelockInfo>,
+        /// proposal id -> time from which passed proposal can be executed
+        executable_at: Mapping<u64, Timestamp>,
+        /// proposal id -> block whose starting token balances are used as vote weight
+        token_snapshot: Mapping<u64, BlockNumber>,
+        /// proposal id -> secretariates vetoing the proposal
+        veto_votes: Mapping<u64, Vec<AccountId>>,
+        /// delegator -> delegate
+        delegation: Mapping<AccountId, AccountId>,
+        /// delegate -> accounts directly delegating to it
+        delegators: Mapping<AccountId, Vec<AccountId>>,
+        /// (proposal id, account) -> voter whose vote carried the weight of the account
+        vote_carried_by: Mapping<(u64, AccountId), AccountId>,
+        /// proposal id -> weight cast by delegates for delegators who can still vote themselves
+        delegated_weight: Mapping<u64, u64>,
     }
 
     impl Proposal {
         // checkout accountid(you will be providing string address)
         #[ink(constructor)]
-        pub fn new(organisation: AccountId) -> Self {
+        pub fn new(organisation: AccountId, voting_mode: VotingMode) -> Self {
             let default_duration: u64 = 172800000; //2 days in millisecond
             let default_quorum: u64 = 10 * 1_000_000; //10 percent for upto 6 decimal places
 
@@ -157,6 +304,7 @@ pub mod proposal {
                 voting_result: Mapping::new(),
                 proposal_duration: default_duration,
                 quorum: default_quorum,
+                voting_mode,
                 org_address: organisation,
                 // project_id: Mapping::new(),
                 project_info: Mapping::new(),
@@ -164,6 +312,23 @@ pub mod proposal {
                 fund_project_info: Mapping::new(),
                 quorum_info: Mapping::new(),
                 span_info: Mapping::new(),
+                voting_mode_info: Mapping::new(),
+                membership_info: Mapping::new(),
+                mint_info: Mapping::new(),
+                close_project_info: Mapping::new(),
+                change_manager_info: Mapping::new(),
+                early_resolution_info: Mapping::new(),
+                early_resolution: Mapping::new(),
+                timelock: 0,
+                veto_body: VetoBody::Owner,
+                timelock_info: Mapping::new(),
+                executable_at: Mapping::new(),
+                veto_votes: Mapping::new(),
+                token_snapshot: Mapping::new(),
+                delegation: Mapping::new(),
+                delegators: Mapping::new(),
+                vote_carried_by: Mapping::new(),
+                delegated_weight: Mapping::new(),
             }
         }
 
@@ -177,6 +342,13 @@ pub mod proposal {
             fund_project: Option<FundProjectInfo>,
             change_quorum: Option<QuorumInfo>,
             change_proposal_span: Option<SpanInfo>,
+            change_voting_mode: Option<VotingMode>,
+            change_membership: Option<MembershipInfo>,
+            mint_tokens: Option<MintInfo>,
+            close_project: Option<CloseProjectInfo>,
+            change_manager: Option<ChangeManagerInfo>,
+            change_early_resolution: Option<EarlyResolutionInfo>,
+            change_timelock: Option<TimelockInfo>,
         ) -> Result<u64> {
             let id = self.next_proposal_id;
             let caller = self.env().caller();
@@ -291,7 +463,191 @@ pub mod proposal {
                         return Err(Error::SpanInfoNotFound);
                     }
                 }
+                ProposalType::ChangeVotingMode => {
+                    if let Some(voting_mode) = change_voting_mode {
+                        if voting_mode == VotingMode::TokenWeighted {
+                            Self::ensure_token_vote_weight(&org_instance)?;
+                        }
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeVotingMode,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            voter_type: Designation::Secretariate,
+                            title,
+                            details,
+                            status: Status::Open,
+                        };
+                        self.voting_mode_info.insert(id, &voting_mode);
+                        self.proposals.insert(id, &proposal_info);
+                        // self.open_proposals_id.push(id);
+
+                        let voting_status = VotingStatus::new(id);
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::VotingModeNotDefined);
+                    }
+                }
+                ProposalType::AddMember
+                | ProposalType::RemoveMember
+                | ProposalType::PromoteToSecretariate
+                | ProposalType::DemoteSecretariate => {
+                    if let Some(membership_info) = change_membership {
+                        let proposal_info = ProposalInfo {
+                            proposal_type: proposal_type.clone(),
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            voter_type: Designation::Secretariate,
+                            title,
+                            details,
+                            status: Status::Open,
+                        };
+                        self.membership_info.insert(id, &membership_info);
+                        self.proposals.insert(id, &proposal_info);
+                        // self.open_proposals_id.push(id);
+
+                        let voting_status = VotingStatus::new(id);
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::MembershipInfoNotFound);
+                    }
+                }
+                ProposalType::MintTokens => {
+                    if let Some(mint_info) = mint_tokens {
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::MintTokens,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            voter_type: Designation::Secretariate,
+                            title,
+                            details,
+                            status: Status::Open,
+                        };
+                        if org_instance.get_token_address().is_none() {
+                            return Err(Error::TokenNotFound)
+                        }
+                        self.mint_info.insert(id, &mint_info);
+                        self.proposals.insert(id, &proposal_info);
+                        // self.open_proposals_id.push(id);
+
+                        let voting_status = VotingStatus::new(id);
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::MintInfoNotFound);
+                    }
+                }
+                ProposalType::CloseProject => {
+                    if let Some(close_info) = close_project {
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::CloseProject,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            voter_type: Designation::Secretariate,
+                            title,
+                            details,
+                            status: Status::Open,
+                        };
+                        if close_info.status != ProjectStatus::Completed
+                            && close_info.status != ProjectStatus::Cancelled
+                        {
+                            return Err(Error::InvalidStatusTransition)
+                        }
+                        org_instance.get_project_address(close_info.project_id)?;
+                        self.close_project_info.insert(id, &close_info);
+                        self.proposals.insert(id, &proposal_info);
+
+                        let voting_status = VotingStatus::new(id);
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::CloseProjectInfoNotFound);
+                    }
+                }
+                ProposalType::ChangeManager => {
+                    if let Some(manager_info) = change_manager {
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeManager,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            voter_type: Designation::Secretariate,
+                            title,
+                            details,
+                            status: Status::Open,
+                        };
+                        org_instance.get_project_address(manager_info.project_id)?;
+                        org_instance.check_member(manager_info.manager, false)?;
+                        self.change_manager_info.insert(id, &manager_info);
+                        self.proposals.insert(id, &proposal_info);
+
+                        let voting_status = VotingStatus::new(id);
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::ManagerInfoNotFound);
+                    }
+                }
+                ProposalType::ChangeEarlyResolution => {
+                    if let Some(early_resolution_info) = change_early_resolution {
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeEarlyResolution,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            voter_type: Designation::Secretariate,
+                            title,
+                            details,
+                            status: Status::Open,
+                        };
+                        self.early_resolution_info.insert(id, &early_resolution_info);
+                        self.proposals.insert(id, &proposal_info);
+
+                        let voting_status = VotingStatus::new(id);
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::EarlyResolutionInfoNotFound);
+                    }
+                }
+                ProposalType::ChangeTimelock => {
+                    if let Some(timelock_info) = change_timelock {
+                        if timelock_info.duration > MAX_TIMELOCK {
+                            return Err(Error::TimelockTooLong);
+                        }
+                        let proposal_info = ProposalInfo {
+                            proposal_type: ProposalType::ChangeTimelock,
+                            proposal_id: id,
+                            proposer: caller,
+                            proposal_span,
+                            voter_type: Designation::Secretariate,
+                            title,
+                            details,
+                            status: Status::Open,
+                        };
+                        self.timelock_info.insert(id, &timelock_info);
+                        self.proposals.insert(id, &proposal_info);
+
+                        let voting_status = VotingStatus::new(id);
+                        self.voting_result.insert(id, &voting_status);
+                        self.next_proposal_id += 1;
+                    } else {
+                        return Err(Error::TimelockInfoNotFound);
+                    }
+                }
             }
+            // tokens moved after creation can't be voted with twice
+            self.token_snapshot.insert(id, &self.env().block_number());
+            EmitEvent::<Proposal>::emit_event(self.env(), ProposalCreated {
+                proposal_id: id,
+                proposer: caller,
+                proposal_type,
+            });
             Ok(id)
         }
 
@@ -320,7 +676,7 @@ pub mod proposal {
                                 Ok(is_true) => {
                                     if is_true {
                                         let update_status =
-                                            self.update_vote_status(caller, vote, proposal_id);
+                                            self.update_vote_status(caller, vote, proposal_id, true);
                                         if update_status.is_err() {
                                             return update_status;
                                         }
@@ -337,7 +693,7 @@ pub mod proposal {
                                 Ok(is_true) => {
                                     if is_true {
                                         let update_status =
-                                            self.update_vote_status(caller, vote, proposal_id);
+                                            self.update_vote_status(caller, vote, proposal_id, false);
                                         if update_status.is_err() {
                                             return update_status;
                                         }
@@ -352,6 +708,8 @@ pub mod proposal {
                             return Err(Error::UnAuthorized);
                         }
                     }
+                    // this vote may have decided the result already
+                    self.update_proposal_open_status(proposal_id);
                 }
                 Status::Rejected => return Err(Error::ProposalRejected),
                 Status::Passed => {
@@ -359,16 +717,115 @@ pub mod proposal {
                     return Err(Error::ProposalClosed);
                 }
                 Status::Executed => return Err(Error::ProposalAlreadyExecuted),
+                Status::Vetoed => return Err(Error::ProposalVetoed),
+            }
+
+            Ok(())
+        }
+
+        /// delegate voting power to another member, replaces earlier delegation(if any)
+        #[ink(message)]
+        pub fn delegate(&mut self, to: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if caller == to {
+                return Err(Error::CannotDelegateToSelf);
+            }
+
+            let org_instance: OrgRef =
+                ink::env::call::FromAccountId::from_account_id(self.org_address);
+            if org_instance.check_member(caller, false).is_err()
+                || org_instance.check_member(to, false).is_err()
+            {
+                return Err(Error::MemberNotFound);
+            }
+
+            // walk up the chain of the delegate, it should not come back to caller
+            let mut chain_depth: u32 = 1;
+            let mut curr = to;
+            while let Some(next) = self.delegation.get(curr) {
+                if next == caller {
+                    return Err(Error::DelegationCycle);
+                }
+                chain_depth += 1;
+                if chain_depth > MAX_DELEGATION_DEPTH {
+                    return Err(Error::DelegationTooDeep);
+                }
+                curr = next;
             }
+            if chain_depth + self.delegation_depth(caller) > MAX_DELEGATION_DEPTH {
+                return Err(Error::DelegationTooDeep);
+            }
+
+            self.remove_delegation(caller);
+            let mut delegators = self.delegators.get(to).unwrap_or_default();
+            delegators.push(caller);
+            self.delegators.insert(to, &delegators);
+            self.delegation.insert(caller, &to);
+            EmitEvent::<Proposal>::emit_event(self.env(), DelegationChanged {
+                delegator: caller,
+                delegate: Some(to),
+            });
 
             Ok(())
         }
 
+        #[ink(message)]
+        pub fn undelegate(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if self.delegation.get(caller).is_none() {
+                return Err(Error::NotDelegated);
+            }
+            self.remove_delegation(caller);
+            EmitEvent::<Proposal>::emit_event(self.env(), DelegationChanged {
+                delegator: caller,
+                delegate: None,
+            });
+            Ok(())
+        }
+
+        fn remove_delegation(&mut self, delegator: AccountId) {
+            if let Some(old_delegate) = self.delegation.get(delegator) {
+                let mut delegators = self.delegators.get(old_delegate).unwrap_or_default();
+                if let Some(index) = delegators.iter().position(|id| *id == delegator) {
+                    delegators.remove(index);
+                }
+                self.delegators.insert(old_delegate, &delegators);
+                self.delegation.remove(delegator);
+            }
+        }
+
+        // number of levels of accounts delegating(directly or through chain) to the account
+        fn delegation_depth(&self, account: AccountId) -> u32 {
+            let mut visited: Vec<AccountId> = vec![account];
+            let mut level: Vec<AccountId> = vec![account];
+            let mut depth: u32 = 0;
+
+            while !level.is_empty() && depth <= MAX_DELEGATION_DEPTH {
+                let mut next_level: Vec<AccountId> = Vec::new();
+                for curr in level {
+                    for delegator in self.delegators.get(curr).unwrap_or_default() {
+                        if !visited.contains(&delegator) {
+                            visited.push(delegator);
+                            next_level.push(delegator);
+                        }
+                    }
+                }
+                if !next_level.is_empty() {
+                    depth += 1;
+                }
+                level = next_level;
+            }
+            depth
+        }
+
+        // caller votes with their own weight and the weight of everyone delegating to them(directly or through a chain)
+        // who has not voted themselves. A direct vote of a delegator takes its weight back from the delegate
         fn update_vote_status(
             &mut self,
             caller: AccountId,
             vote: Vote,
             proposal_id: u64,
+            secretariate: bool,
         ) -> Result<()> {
             let mut curr_voting_result = self.voting_result.get(proposal_id).unwrap();
             let voterinfo = curr_voting_result
@@ -379,29 +836,122 @@ pub mod proposal {
             if let Some(_) = voterinfo {
                 return Err(Error::AlreadyVoted);
             } else {
+                let org_instance: OrgRef =
+                    ink::env::call::FromAccountId::from_account_id(self.org_address);
+                let represented = self.represented_accounts(caller, &curr_voting_result.voters);
+
+                let mut weight: u64 = 0;
+                let mut delegated_weight = self.delegated_weight.get(proposal_id).unwrap_or(0);
+                for account in represented {
+                    // delegators who can not vote on this proposal don't add weight
+                    if account != caller
+                        && !matches!(org_instance.check_member(account, secretariate), Ok(true))
+                    {
+                        continue;
+                    }
+                    let account_weight = self.vote_weight(account, proposal_id)?;
+
+                    if let Some(prev_voter) = self.vote_carried_by.get((proposal_id, account)) {
+                        if let Some(prev_info) = curr_voting_result
+                            .voters
+                            .iter_mut()
+                            .find(|voteinfo| voteinfo.voter == prev_voter)
+                        {
+                            prev_info.weight = prev_info.weight.saturating_sub(account_weight);
+                            let prev_vote = prev_info.vote;
+                            remove_from_tally(&mut curr_voting_result.votes, prev_vote, account_weight);
+                        }
+                        delegated_weight = delegated_weight.saturating_sub(account_weight);
+                    }
+                    if account != caller {
+                        delegated_weight += account_weight;
+                    }
+                    self.vote_carried_by.insert((proposal_id, account), &caller);
+                    weight += account_weight;
+                }
+
                 let voter_info = VoteInfo {
                     voter: caller,
                     vote: vote.clone(),
+                    weight,
                 };
                 curr_voting_result.voters.push(voter_info);
 
                 match vote.clone() {
                     Vote::Yes => {
-                        curr_voting_result.votes.yes += 1;
+                        curr_voting_result.votes.yes += weight;
                     }
                     Vote::No => {
-                        curr_voting_result.votes.no += 1;
+                        curr_voting_result.votes.no += weight;
                     }
                     Vote::Abstain => {
-                        curr_voting_result.votes.abstain += 1;
+                        curr_voting_result.votes.abstain += weight;
                     }
                 }
+                EmitEvent::<Proposal>::emit_event(self.env(), VoteCast {
+                    proposal_id,
+                    voter: caller,
+                    vote,
+                    weight,
+                });
+                self.delegated_weight.insert(proposal_id, &delegated_weight);
             }
             self.voting_result.insert(proposal_id, &curr_voting_result);
 
             Ok(())
         }
 
+        // voter and their delegation tree, stopping at delegators who have already voted themselves
+        fn represented_accounts(&self, voter: AccountId, voters: &[VoteInfo]) -> Vec<AccountId> {
+            let mut accounts: Vec<AccountId> = vec![voter];
+            let mut level: Vec<AccountId> = vec![voter];
+            let mut depth: u32 = 0;
+
+            while !level.is_empty() && depth < MAX_DELEGATION_DEPTH {
+                let mut next_level: Vec<AccountId> = Vec::new();
+                for account in level {
+                    for delegator in self.delegators.get(account).unwrap_or_default() {
+                        let already_voted = voters.iter().any(|voteinfo| voteinfo.voter == delegator);
+                        if !already_voted && !accounts.contains(&delegator) {
+                            accounts.push(delegator);
+                            next_level.push(delegator);
+                        }
+                    }
+                }
+                level = next_level;
+                depth += 1;
+            }
+            accounts
+        }
+
+        // token weighted voting needs someone holding at least one whole token, else nobody could vote
+        fn ensure_token_vote_weight(org_instance: &OrgRef) -> Result<()> {
+            if org_instance.get_token_address().is_none() {
+                return Err(Error::TokenNotFound);
+            }
+            if org_instance.total_token_vote_weight() == 0 {
+                return Err(Error::NoTokenVoteWeight);
+            }
+            Ok(())
+        }
+
+        fn vote_weight(&self, voter: AccountId, proposal_id: u64) -> Result<u64> {
+            match self.voting_mode {
+                VotingMode::Equal => Ok(1),
+                VotingMode::RatingWeighted => {
+                    let org_instance: OrgRef =
+                        ink::env::call::FromAccountId::from_account_id(self.org_address);
+                    org_instance.get_vote_weight(voter)
+                }
+                VotingMode::TokenWeighted => {
+                    let org_instance: OrgRef =
+                        ink::env::call::FromAccountId::from_account_id(self.org_address);
+                    let snapshot = self.token_snapshot.get(proposal_id).unwrap_or_default();
+                    Ok(org_instance.get_token_vote_weight_at(voter, snapshot))
+                }
+            }
+        }
+
         // passed proposal will get executed
         // can be called by anyone
         // call respective functions to execute the proposal
@@ -424,7 +974,14 @@ pub mod proposal {
                 Status::Rejected => {
                     return Err(Error::ProposalRejected);
                 }
+                Status::Vetoed => {
+                    return Err(Error::ProposalVetoed);
+                }
                 Status::Passed => {
+                    let executable_at = self.executable_at.get(proposal_id).unwrap_or_default();
+                    if self.env().block_timestamp() < executable_at {
+                        return Err(Error::TimelockActive);
+                    }
                     match proposal.proposal_type {
                         ProposalType::AddProject => {
                             let project_info = self.project_info.get(proposal_id).unwrap();
@@ -436,6 +993,7 @@ pub mod proposal {
                                     project_info.fund_asked,
                                     project_info.strength,
                                     proposal.proposer,
+                                    self.voting_mode,
                                 )
                                 .unwrap();
                             proposal.status = Status::Executed;
@@ -474,7 +1032,119 @@ pub mod proposal {
                             proposal.status = Status::Executed;
                             self.proposals.insert(proposal.proposal_id, &proposal);
                         }
+                        ProposalType::ChangeVotingMode => {
+                            let voting_mode = self.voting_mode_info.get(proposal_id).unwrap();
+                            if voting_mode == VotingMode::TokenWeighted {
+                                Self::ensure_token_vote_weight(&org_instance)?;
+                            }
+
+                            self.voting_mode = voting_mode;
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::AddMember => {
+                            let membership_info = self.membership_info.get(proposal_id).unwrap();
+
+                            let add_status = org_instance.add_member_by_proposal(
+                                membership_info.member_address,
+                                membership_info.name,
+                                membership_info.company_id,
+                                membership_info.rating,
+                            );
+                            if add_status.is_err() {
+                                return add_status
+                            }
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::RemoveMember => {
+                            let membership_info = self.membership_info.get(proposal_id).unwrap();
+
+                            let remove_status =
+                                org_instance.remove_member_by_proposal(membership_info.member_address);
+                            if remove_status.is_err() {
+                                return remove_status
+                            }
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::PromoteToSecretariate => {
+                            let membership_info = self.membership_info.get(proposal_id).unwrap();
+
+                            let promote_status = org_instance.add_secretariate_by_proposal(
+                                membership_info.member_address,
+                                membership_info.name,
+                                membership_info.company_id,
+                                membership_info.rating,
+                            );
+                            if promote_status.is_err() {
+                                return promote_status
+                            }
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::DemoteSecretariate => {
+                            let membership_info = self.membership_info.get(proposal_id).unwrap();
+
+                            let demote_status = org_instance
+                                .remove_secretariate_by_proposal(membership_info.member_address);
+                            if demote_status.is_err() {
+                                return demote_status
+                            }
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::MintTokens => {
+                            let mint_info = self.mint_info.get(proposal_id).unwrap();
+
+                            let mint_status = org_instance
+                                .mint_token_by_proposal(mint_info.member_address, mint_info.amount);
+                            if mint_status.is_err() {
+                                return mint_status
+                            }
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::CloseProject => {
+                            let close_info = self.close_project_info.get(proposal_id).unwrap();
+
+                            org_instance
+                                .close_project_by_proposal(close_info.project_id, close_info.status)?;
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::ChangeManager => {
+                            let manager_info = self.change_manager_info.get(proposal_id).unwrap();
+
+                            org_instance
+                                .change_manager_by_proposal(manager_info.project_id, manager_info.manager)?;
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::ChangeEarlyResolution => {
+                            let early_resolution_info =
+                                self.early_resolution_info.get(proposal_id).unwrap();
+
+                            self.early_resolution.insert(
+                                early_resolution_info.proposal_type,
+                                &early_resolution_info.enabled,
+                            );
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
+                        ProposalType::ChangeTimelock => {
+                            let timelock_info = self.timelock_info.get(proposal_id).unwrap();
+
+                            self.timelock = timelock_info.duration;
+                            self.veto_body = timelock_info.veto_body;
+                            proposal.status = Status::Executed;
+                            self.proposals.insert(proposal.proposal_id, &proposal);
+                        }
                     }
+                    EmitEvent::<Proposal>::emit_event(self.env(), ProposalStatusChanged {
+                        proposal_id,
+                        status: Status::Executed,
+                    });
                 }
                 Status::Executed => {
                     return Err(Error::ProposalAlreadyExecuted);
@@ -484,6 +1154,70 @@ pub mod proposal {
             Ok(())
         }
 
+        // owner or secretariates(depending on veto body) cancel a passed proposal before its timelock ends
+        #[ink(message)]
+        pub fn veto_proposal(&mut self, proposal_id: u64) -> Result<()> {
+            let caller = self.env().caller();
+
+            if self.proposals.get(proposal_id).is_none() {
+                return Err(Error::ProposalNotFound);
+            }
+
+            self.update_proposal_open_status(proposal_id);
+            let mut proposal = self.proposals.get(proposal_id).unwrap();
+
+            match proposal.status {
+                Status::Open => return Err(Error::ProposalRunning),
+                Status::Rejected => return Err(Error::ProposalRejected),
+                Status::Executed => return Err(Error::ProposalAlreadyExecuted),
+                Status::Vetoed => return Err(Error::ProposalVetoed),
+                Status::Passed => {}
+            }
+            let executable_at = self.executable_at.get(proposal_id).unwrap_or_default();
+            if self.env().block_timestamp() >= executable_at {
+                return Err(Error::TimelockExpired);
+            }
+
+            let org_instance: OrgRef =
+                ink::env::call::FromAccountId::from_account_id(self.org_address);
+            let vetoed = match self.veto_body {
+                VetoBody::Owner => {
+                    if caller != org_instance.get_org_owner() {
+                        return Err(Error::UnAuthorized);
+                    }
+                    true
+                }
+                VetoBody::Secretariate => {
+                    if !org_instance.check_member(caller, true)? {
+                        return Err(Error::NotASecretariate);
+                    }
+                    let mut veto_votes = self.veto_votes.get(proposal_id).unwrap_or_default();
+                    // votes of demoted or removed secretariates don't count anymore
+                    veto_votes.retain(|voter| org_instance.check_member(*voter, true).unwrap_or(false));
+                    if veto_votes.contains(&caller) {
+                        return Err(Error::AlreadyVoted);
+                    }
+                    veto_votes.push(caller);
+                    self.veto_votes.insert(proposal_id, &veto_votes);
+                    EmitEvent::<Proposal>::emit_event(self.env(), VetoCast {
+                        proposal_id,
+                        voter: caller,
+                    });
+                    // two third of the secretariates are needed to cancel it
+                    veto_votes.len() as u64 * 3 >= org_instance.total_secretariate() * 2
+                }
+            };
+            if vetoed {
+                proposal.status = Status::Vetoed;
+                self.proposals.insert(proposal.proposal_id, &proposal);
+                EmitEvent::<Proposal>::emit_event(self.env(), ProposalStatusChanged {
+                    proposal_id,
+                    status: Status::Vetoed,
+                });
+            }
+            Ok(())
+        }
+
         // voting is only done by secretaries of organisation
         fn update_proposal_open_status(&mut self, proposal_id: u64) {
             let mut proposal = self.proposals.get(proposal_id).unwrap();
@@ -492,32 +1226,85 @@ pub mod proposal {
 
             let org_instance: OrgRef = ink::env::call::FromAccountId::from_account_id(org_address);
 
-            if self.env().block_timestamp() > proposal.proposal_span.end_time {
+            let expired = self.env().block_timestamp() > proposal.proposal_span.end_time;
+            let early_resolution = self
+                .early_resolution
+                .get(&proposal.proposal_type)
+                .unwrap_or(false);
+            if expired || early_resolution {
                 
                 let mut total_voters = 0;
-                match proposal.voter_type {
-                    Designation::Secretariate => {
+                match (proposal.voter_type.clone(), self.voting_mode) {
+                    (Designation::Secretariate, VotingMode::Equal) => {
                         total_voters = org_instance.total_secretariate();
                     },
-                    Designation::Member => {
+                    (Designation::Secretariate, VotingMode::RatingWeighted) => {
+                        total_voters = org_instance.total_secretariate_weight();
+                    },
+                    (Designation::Member, VotingMode::Equal) => {
                         total_voters = org_instance.total_members();
                     },
+                    (Designation::Member, VotingMode::RatingWeighted) => {
+                        total_voters = org_instance.total_member_weight();
+                    },
+                    (voter_type, VotingMode::TokenWeighted) => {
+                        let snapshot = self.token_snapshot.get(proposal_id).unwrap_or_default();
+                        total_voters = org_instance.total_token_vote_weight_at(
+                            snapshot,
+                            voter_type == Designation::Secretariate,
+                        );
+                    },
                     _ => {} 
                 }
                 match proposal.status {
                     Status::Open => {
                         // Till 6 digit after decimal
                         let factor: u64 = 1_000_000;
-                        let voted_quorum: u64 =
-                            (curr_voting_result.voters.len() as u64 * factor) / total_voters;
-                        if curr_voting_result.votes.no <= curr_voting_result.votes.yes
-                            && (self.quorum) <= voted_quorum
-                        {
-                            proposal.status = Status::Passed;
-                        } else {
+                        // votes are already weighted according to the voting mode
+                        let votes = &curr_voting_result.votes;
+                        let cast = votes.yes + votes.no + votes.abstain;
+                        if total_voters == 0 {
+                            // nobody is able to vote(e.g. no member holds a whole governance token)
                             proposal.status = Status::Rejected;
+                        } else if expired {
+                            let voted_quorum: u64 = cast.saturating_mul(factor) / total_voters;
+                            if votes.no <= votes.yes && (self.quorum) <= voted_quorum {
+                                proposal.status = Status::Passed;
+                            } else {
+                                proposal.status = Status::Rejected;
+                            }
+                        } else {
+                            let voted_quorum: u64 = cast.saturating_mul(factor) / total_voters;
+                            // delegators can still take their weight back from their delegate and vote the other way
+                            let pending = self.delegated_weight.get(proposal_id).unwrap_or(0);
+                            // closed early only if uncast and pending votes all going one way can't flip the result
+                            let uncast = total_voters.saturating_sub(cast) + pending;
+                            if votes.no + uncast <= votes.yes.saturating_sub(pending)
+                                && (self.quorum) <= voted_quorum
+                            {
+                                proposal.status = Status::Passed;
+                            } else if votes.yes + uncast < votes.no.saturating_sub(pending) {
+                                // missing quorum would reject it as well
+                                proposal.status = Status::Rejected;
+                            } else {
+                                return;
+                            }
+                        }
+                        if proposal.status == Status::Passed {
+                            // timelock runs from the moment voting got decided
+                            let passed_at = if expired {
+                                proposal.proposal_span.end_time
+                            } else {
+                                self.env().block_timestamp()
+                            };
+                            self.executable_at
+                                .insert(proposal_id, &passed_at.saturating_add(self.timelock));
                         }
                         self.proposals.insert(proposal.proposal_id, &proposal);
+                        EmitEvent::<Proposal>::emit_event(self.env(), ProposalStatusChanged {
+                            proposal_id,
+                            status: proposal.status,
+                        });
                     }
                     _ => {}
                 }
@@ -574,6 +1361,108 @@ pub mod proposal {
             }
         }
         #[ink(message)]
+        pub fn get_delegate(&self, delegator: AccountId) -> Option<AccountId> {
+            self.delegation.get(delegator)
+        }
+        #[ink(message)]
+        pub fn get_delegators(&self, delegate: AccountId) -> Vec<AccountId> {
+            self.delegators.get(delegate).unwrap_or_default()
+        }
+        #[ink(message)]
+        pub fn get_voting_mode(&self) -> VotingMode {
+            self.voting_mode
+        }
+        #[ink(message)]
+        pub fn get_voting_mode_info(&self, proposal_id: u64) -> Result<VotingMode> {
+            let voting_mode_info = self.voting_mode_info.get(proposal_id);
+            if let Some(info) = voting_mode_info {
+                return Ok(info);
+            } else {
+                return Err(Error::VotingModeNotDefined);
+            }
+        }
+        #[ink(message)]
+        pub fn get_membership_info(&self, proposal_id: u64) -> Result<MembershipInfo> {
+            let membership_info = self.membership_info.get(proposal_id);
+            if let Some(info) = membership_info {
+                return Ok(info);
+            } else {
+                return Err(Error::MembershipInfoNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn get_mint_info(&self, proposal_id: u64) -> Result<MintInfo> {
+            let mint_info = self.mint_info.get(proposal_id);
+            if let Some(info) = mint_info {
+                return Ok(info);
+            } else {
+                return Err(Error::MintInfoNotFound);
+            }
+        }
+        // passed proposals return their actual executable time, open ones the time if passed at the end of voting
+        // (proposal types with early resolution can become executable sooner)
+        #[ink(message)]
+        pub fn get_executable_time(&self, proposal_id: u64) -> Option<Timestamp> {
+            let proposal = self.proposals.get(proposal_id)?;
+            match proposal.status {
+                Status::Open => Some(proposal.proposal_span.end_time.saturating_add(self.timelock)),
+                Status::Passed | Status::Executed => self.executable_at.get(proposal_id),
+                Status::Rejected | Status::Vetoed => None,
+            }
+        }
+        #[ink(message)]
+        pub fn get_timelock(&self) -> Timestamp {
+            self.timelock
+        }
+        #[ink(message)]
+        pub fn get_veto_body(&self) -> VetoBody {
+            self.veto_body
+        }
+        #[ink(message)]
+        pub fn get_veto_votes(&self, proposal_id: u64) -> Vec<AccountId> {
+            self.veto_votes.get(proposal_id).unwrap_or_default()
+        }
+        #[ink(message)]
+        pub fn get_timelock_info(&self, proposal_id: u64) -> Result<TimelockInfo> {
+            let timelock_info = self.timelock_info.get(proposal_id);
+            if let Some(info) = timelock_info {
+                return Ok(info);
+            } else {
+                return Err(Error::TimelockInfoNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn get_early_resolution_info(&self, proposal_id: u64) -> Result<EarlyResolutionInfo> {
+            let early_resolution_info = self.early_resolution_info.get(proposal_id);
+            if let Some(info) = early_resolution_info {
+                return Ok(info);
+            } else {
+                return Err(Error::EarlyResolutionInfoNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn is_early_resolution_enabled(&self, proposal_type: ProposalType) -> bool {
+            self.early_resolution.get(proposal_type).unwrap_or(false)
+        }
+        #[ink(message)]
+        pub fn get_change_manager_info(&self, proposal_id: u64) -> Result<ChangeManagerInfo> {
+            let manager_info = self.change_manager_info.get(proposal_id);
+            if let Some(info) = manager_info {
+                return Ok(info);
+            } else {
+                return Err(Error::ManagerInfoNotFound);
+            }
+        }
+        #[ink(message)]
+        pub fn get_close_project_info(&self, proposal_id: u64) -> Result<CloseProjectInfo> {
+            let close_info = self.close_project_info.get(proposal_id);
+            if let Some(info) = close_info {
+                return Ok(info);
+            } else {
+                return Err(Error::CloseProjectInfoNotFound);
+            }
+        }
+        #[ink(message)]
         pub fn get_project_id(&self, proposal_id: u64) -> Result<u32> {
             let project_id = self.project_ids.get(proposal_id);
             if let Some(info) = project_id {
@@ -583,4 +1472,20 @@ pub mod proposal {
             }
         }
     }
+    // Helper Functions     Helper Functions    Helper Functions    Helper Functions
+    // Helper Functions     Helper Functions    Helper Functions    Helper Functions
+
+    fn remove_from_tally(votes: &mut Votes, vote: Vote, weight: u64) {
+        match vote {
+            Vote::Yes => {
+                votes.yes = votes.yes.saturating_sub(weight);
+            }
+            Vote::No => {
+                votes.no = votes.no.saturating_sub(weight);
+            }
+            Vote::Abstain => {
+                votes.abstain = votes.abstain.saturating_sub(weight);
+            }
+        }
+    }
 }